@@ -0,0 +1,147 @@
+use std::{env, process::ExitCode};
+
+use common::{
+    input::{Cache, Cached, Http, InputError, InputProvider, Session, Source, YEAR},
+    solution::{run, Part},
+};
+
+const USAGE: &str = "Usage: aoc run <day> [--part 1|2] [--input PATH|-]
+       aoc fetch <day>";
+
+#[derive(Debug)]
+enum Command {
+    Run(Args),
+    /// Downloads a day's input into the cache, using the session cookie in `AOC_SESSION`.
+    Fetch {
+        day: u8,
+    },
+}
+
+#[derive(Debug)]
+struct Args {
+    day: u8,
+    part: Option<Part>,
+    input: Source,
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let fetch = match args.next().as_deref() {
+        Some("run") => false,
+        Some("fetch") => true,
+        Some(command) => return Err(format!("Unknown command: {command}")),
+        None => return Err("Missing command".to_string()),
+    };
+
+    let day = args.next().ok_or("Missing day")?;
+    let day = day
+        .parse::<u8>()
+        .map_err(|_| format!("Invalid day: {day}"))?;
+
+    if fetch {
+        return match args.next() {
+            Some(other) => Err(format!("Unknown argument: {other}")),
+            None => Ok(Command::Fetch { day }),
+        };
+    }
+
+    let mut part = None;
+    let mut input = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => {
+                part = Some(match args.next().as_deref() {
+                    Some("1") => Part::One,
+                    Some("2") => Part::Two,
+                    Some(other) => return Err(format!("Invalid part: {other}")),
+                    None => return Err("Missing value for --part".to_string()),
+                })
+            }
+            "--input" => input = Some(args.next().ok_or("Missing value for --input")?),
+            other => return Err(format!("Unknown argument: {other}")),
+        }
+    }
+
+    Ok(Command::Run(Args {
+        day,
+        part,
+        input: Source::from_arg(day, input.as_deref()),
+    }))
+}
+
+/// Parses an input and prints the answers to one or both parts, like [`run`].
+type Runner = fn(&str, Option<Part>) -> Result<(), String>;
+
+/// The solution for `day`, or `None` if that day hasn't been solved.
+fn solution(day: u8) -> Option<Runner> {
+    match day {
+        1 => Some(run::<day_1::Day1>),
+        2 => Some(run::<day_2::Day2>),
+        3 => Some(run::<day_3::Day3>),
+        4 => Some(run::<day_4::Day4>),
+        5 => Some(run::<day_5::Day5>),
+        6 => Some(run::<day_6::Day6>),
+        7 => Some(run::<day_7::Day7>),
+        8 => Some(run::<day_8::Day8>),
+        9 => Some(run::<day_9::Day9>),
+        10 => Some(run::<day_10::Day10>),
+        11 => Some(run::<day_11::Day11>),
+        12 => Some(run::<day_12::Day12>),
+        13 => Some(run::<day_13::Day13>),
+        14 => Some(run::<day_14::Day14>),
+        15 => Some(run::<day_15::Day15>),
+        16 => Some(run::<day_16::Day16>),
+        _ => None,
+    }
+}
+
+fn fetch(day: u8) -> Result<(), InputError> {
+    let cache = Cache::default();
+    let provider = Cached::new(cache.clone(), Http::new(Session::from_env()?));
+
+    provider.input(YEAR, day)?;
+    println!("Saved input to {}", cache.path(YEAR, day).display());
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args(env::args().skip(1)) {
+        Ok(Command::Run(args)) => args,
+        Ok(Command::Fetch { day }) => {
+            return match fetch(day) {
+                Ok(()) => ExitCode::SUCCESS,
+                Err(err) => {
+                    eprintln!("{err}");
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Err(err) => {
+            eprintln!("{err}\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    // Check the day first, so a missing solution isn't reported as missing input
+    let Some(solve) = solution(args.day) else {
+        eprintln!("No solution for day {}", args.day);
+        return ExitCode::FAILURE;
+    };
+
+    let input = match args.input.read() {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match solve(&input, args.part) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
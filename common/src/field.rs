@@ -1,4 +1,13 @@
-use std::{fmt::Debug, iter::once};
+use std::{
+    fmt::{Debug, Display},
+    iter::{once, Enumerate},
+    mem,
+    slice::IterMut,
+    str::FromStr,
+    vec::IntoIter,
+};
+
+use crate::parse::ParseError;
 
 #[derive(Clone, Eq, PartialEq, Hash)]
 pub struct Field<T> {
@@ -16,7 +25,7 @@ where
             .rows()
             .map(|row| {
                 once('\t')
-                    .chain(row.map(|tile| Into::into(tile)).chain(once('\n')))
+                    .chain(row.map(Into::into).chain(once('\n')))
                     .collect::<String>()
             })
             .collect::<String>();
@@ -29,8 +38,110 @@ where
     }
 }
 
+/// One of the four directions along the rows and columns of a field, where up is towards row 0.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn opposite(self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+}
+
+/// One of the eight directions to the tiles surrounding another, including the diagonals.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Dir8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Dir8 {
+    pub const ALL: [Dir8; 8] = [
+        Dir8::Up,
+        Dir8::UpRight,
+        Dir8::Right,
+        Dir8::DownRight,
+        Dir8::Down,
+        Dir8::DownLeft,
+        Dir8::Left,
+        Dir8::UpLeft,
+    ];
+
+    /// The change in x and y from moving one tile in this direction.
+    pub fn offset(self) -> (isize, isize) {
+        match self {
+            Dir8::Up => (0, -1),
+            Dir8::UpRight => (1, -1),
+            Dir8::Right => (1, 0),
+            Dir8::DownRight => (1, 1),
+            Dir8::Down => (0, 1),
+            Dir8::DownLeft => (-1, 1),
+            Dir8::Left => (-1, 0),
+            Dir8::UpLeft => (-1, -1),
+        }
+    }
+}
+
+impl From<Direction> for Dir8 {
+    fn from(dir: Direction) -> Self {
+        match dir {
+            Direction::Up => Dir8::Up,
+            Direction::Down => Dir8::Down,
+            Direction::Left => Dir8::Left,
+            Direction::Right => Dir8::Right,
+        }
+    }
+}
+
+/// The error for a character that doesn't correspond to any tile, for tile types to use in their
+/// `TryFrom<char>` implementations.
+#[derive(Debug)]
+pub struct TileError(pub char);
+
+impl Display for TileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Could not create tile from char: {}", self.0)
+    }
+}
+
 impl<T> Field<T> {
+    /// Creates a field from its tiles in row-major order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there aren't exactly `width * height` tiles.
     pub fn new(height: usize, width: usize, input: Vec<T>) -> Self {
+        assert_eq!(
+            width * height,
+            input.len(),
+            "a {width}x{height} field needs {} tiles but was given {}",
+            width * height,
+            input.len()
+        );
+
         Self {
             width,
             height,
@@ -38,28 +149,140 @@ impl<T> Field<T> {
         }
     }
 
+    /// Creates a field with every tile set to `value`.
+    pub fn new_filled(height: usize, width: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(height, width, vec![value; width * height])
+    }
+
+    /// Parses a block of text with one row per line, converting each character with `tile`.
+    ///
+    /// Every row must be as wide as the first, and any character `tile` rejects is reported as an
+    /// unknown tile.
+    pub fn from_str_with<E>(
+        input: &str,
+        mut tile: impl FnMut(char) -> Result<T, E>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut field = Vec::new();
+
+        for (y, line) in input.lines().enumerate() {
+            let row_start = field.len();
+
+            for (x, (idx, char)) in line.char_indices().enumerate() {
+                if let Some(width) = width.filter(|width| x >= *width) {
+                    return Err(ParseError::new(
+                        y + 1,
+                        x + 1,
+                        &line[idx..],
+                        format!("row longer than {width} tiles"),
+                    ));
+                }
+
+                field.push(
+                    tile(char).map_err(|_| ParseError::new(y + 1, x + 1, char, "unknown tile"))?,
+                );
+            }
+
+            let row_width = field.len() - row_start;
+
+            match width {
+                None => width = Some(row_width),
+                Some(width) if row_width < width => {
+                    return Err(ParseError::new(
+                        y + 1,
+                        row_width + 1,
+                        "",
+                        format!("row shorter than {width} tiles"),
+                    ))
+                }
+                Some(_) => {}
+            }
+
+            height += 1;
+        }
+
+        Ok(Self::new(height, width.unwrap_or(0), field))
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `(x, y)` is outside the field. Use [`Field::try_get`] for positions that might be.
     pub fn get(&self, (x, y): (usize, usize)) -> &T {
-        &self.field[y * self.width + x]
+        match self.try_get((x, y)) {
+            Some(tile) => tile,
+            None => self.out_of_bounds((x, y)),
+        }
     }
 
+    /// # Panics
+    ///
+    /// Panics if `(x, y)` is outside the field. Use [`Field::try_get_mut`] for positions that might
+    /// be.
     pub fn get_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
-        &mut self.field[y * self.width + x]
+        if x < self.width && y < self.height {
+            &mut self.field[y * self.width + x]
+        } else {
+            self.out_of_bounds((x, y))
+        }
+    }
+
+    pub fn try_get(&self, (x, y): (usize, usize)) -> Option<&T> {
+        if x < self.width && y < self.height {
+            Some(&self.field[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    pub fn try_get_mut(&mut self, (x, y): (usize, usize)) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            Some(&mut self.field[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// Gets the tile at a position that may be negative, returning `None` outside the field.
+    pub fn get_signed(&self, (x, y): (isize, isize)) -> Option<&T> {
+        self.try_get((usize::try_from(x).ok()?, usize::try_from(y).ok()?))
+    }
+
+    /// Gets the tile at a position as if the field repeated forever in every direction, so
+    /// `(-1, 0)` is the last tile of the first row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the field is empty.
+    pub fn get_wrapping(&self, (x, y): (isize, isize)) -> &T {
+        assert!(
+            self.width > 0 && self.height > 0,
+            "cannot wrap around an empty field"
+        );
+
+        let x = x.rem_euclid(self.width as isize) as usize;
+        let y = y.rem_euclid(self.height as isize) as usize;
+
+        &self.field[y * self.width + x]
     }
 
     pub fn rows(&self) -> FieldRowIter<'_, T> {
         FieldRowIter {
-            field: &self,
+            field: self,
             y: 0,
-            y_back: self.height - 1,
+            y_end: self.height,
         }
     }
 
     pub fn row(&self, y: usize) -> FieldRowIterIndividual<'_, T> {
         FieldRowIterIndividual {
-            field: &self,
+            field: self,
             y,
             x: 0,
-            x_back: self.width - 1,
+            x_end: self.width,
         }
     }
 
@@ -69,18 +292,18 @@ impl<T> Field<T> {
 
     pub fn cols(&self) -> FieldColIter<'_, T> {
         FieldColIter {
-            field: &self,
+            field: self,
             x: 0,
-            x_back: self.width - 1,
+            x_end: self.width,
         }
     }
 
     pub fn col(&self, x: usize) -> FieldColIterIndividual<'_, T> {
         FieldColIterIndividual {
-            field: &self,
+            field: self,
             x,
             y: 0,
-            y_back: self.height - 1,
+            y_end: self.height,
         }
     }
 
@@ -90,14 +313,217 @@ impl<T> Field<T> {
 
     pub fn iter(&self) -> FieldIter<'_, T> {
         FieldIter {
-            field: &self,
+            field: self,
             idx: 0,
-            idx_back: self.field.len() - 1,
+            idx_end: self.field.len(),
         }
     }
 
+    pub fn iter_mut(&mut self) -> FieldIterMut<'_, T> {
+        FieldIterMut {
+            iter: self.field.iter_mut().enumerate(),
+            width: self.width,
+        }
+    }
+
+    /// Iterates over each row as a mutable slice, from top to bottom.
+    pub fn rows_mut(&mut self) -> FieldRowIterMut<'_, T> {
+        FieldRowIterMut {
+            rest: &mut self.field,
+            width: self.width,
+            rows: self.height,
+        }
+    }
+
+    /// Iterates over each column, from left to right, as the tiles in it from top to bottom.
+    ///
+    /// Columns aren't contiguous, so this collects references to every tile up front. Prefer
+    /// [`Field::rows_mut`] or [`Field::swap`] where they will do.
+    pub fn cols_mut(&mut self) -> IntoIter<Vec<&mut T>> {
+        let mut cols = (0..self.width)
+            .map(|_| Vec::with_capacity(self.height))
+            .collect::<Vec<_>>();
+
+        for row in self.rows_mut() {
+            for (col, tile) in cols.iter_mut().zip(row) {
+                col.push(tile);
+            }
+        }
+
+        cols.into_iter()
+    }
+
+    /// Replaces every tile that is set in `layer` with the tile from `layer`, leaving the rest.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `layer` isn't the same size as this field.
+    pub fn overlay(&mut self, layer: &Field<Option<T>>)
+    where
+        T: Clone,
+    {
+        assert!(
+            self.width == layer.width && self.height == layer.height,
+            "cannot overlay a {}x{} field onto a {}x{} field",
+            layer.width,
+            layer.height,
+            self.width,
+            self.height
+        );
+
+        for (tile, top) in self.field.iter_mut().zip(&layer.field) {
+            if let Some(top) = top {
+                tile.clone_from(top);
+            }
+        }
+    }
+
+    /// Creates a field of the same size with `f` applied to every tile.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Field<U> {
+        Field::new(self.height, self.width, self.field.iter().map(f).collect())
+    }
+
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.field.fill(value);
+    }
+
+    /// # Panics
+    ///
+    /// Panics if either position is outside the field.
+    pub fn swap(&mut self, a: (usize, usize), b: (usize, usize)) {
+        let idx = |(x, y): (usize, usize)| {
+            if x < self.width && y < self.height {
+                y * self.width + x
+            } else {
+                self.out_of_bounds((x, y))
+            }
+        };
+
+        let (a, b) = (idx(a), idx(b));
+        self.field.swap(a, b);
+    }
+
+    /// Swaps the rows and columns of the field, mirroring it along the diagonal from the top left.
+    ///
+    /// Square fields are transposed in place.
+    pub fn transpose(&mut self) {
+        if self.width == self.height {
+            for y in 0..self.height {
+                for x in y + 1..self.width {
+                    self.swap((x, y), (y, x));
+                }
+            }
+        } else {
+            let mut tiles = mem::take(&mut self.field)
+                .into_iter()
+                .map(Some)
+                .collect::<Vec<_>>();
+
+            self.field = (0..tiles.len())
+                .map(|idx| tiles[(idx % self.height) * self.width + idx / self.height].take())
+                .map(Option::unwrap)
+                .collect();
+
+            mem::swap(&mut self.width, &mut self.height);
+        }
+    }
+
+    /// Rotates the field a quarter turn clockwise, so the left column becomes the top row.
+    pub fn rotate_cw(&mut self) {
+        self.transpose();
+        self.flip_h();
+    }
+
+    /// Rotates the field a quarter turn anticlockwise, so the top row becomes the left column.
+    pub fn rotate_ccw(&mut self) {
+        self.transpose();
+        self.flip_v();
+    }
+
+    /// Mirrors the field left to right.
+    pub fn flip_h(&mut self) {
+        for row in self.rows_mut() {
+            row.reverse();
+        }
+    }
+
+    /// Mirrors the field top to bottom.
+    pub fn flip_v(&mut self) {
+        for y in 0..self.height / 2 {
+            let (top, bottom) = self.field.split_at_mut((self.height - 1 - y) * self.width);
+
+            top[y * self.width..(y + 1) * self.width].swap_with_slice(&mut bottom[..self.width]);
+        }
+    }
+
+    /// Iterates over the positions of every tile matching `pred`, in the same order as
+    /// [`Field::iter`].
+    pub fn positions_where<'a>(
+        &'a self,
+        mut pred: impl FnMut(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        self.iter()
+            .filter(move |(_, tile)| pred(tile))
+            .map(|(pos, _)| pos)
+    }
+
+    /// Iterates over the index of every row where `is_empty` holds for all of its tiles.
+    pub fn empty_rows<'a>(
+        &'a self,
+        is_empty: impl Fn(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = usize> + 'a {
+        (0..self.height).filter(move |y| self.row(*y).all(&is_empty))
+    }
+
+    /// Iterates over the index of every column where `is_empty` holds for all of its tiles.
+    pub fn empty_cols<'a>(
+        &'a self,
+        is_empty: impl Fn(&T) -> bool + 'a,
+    ) -> impl Iterator<Item = usize> + 'a {
+        (0..self.width).filter(move |x| self.col(*x).all(&is_empty))
+    }
+
+    /// Moves one tile from `(x, y)` in `dir`, returning `None` if that would leave the field.
+    pub fn step(&self, (x, y): (usize, usize), dir: impl Into<Dir8>) -> Option<(usize, usize)> {
+        let (dx, dy) = dir.into().offset();
+        let x = x.checked_add_signed(dx)?;
+        let y = y.checked_add_signed(dy)?;
+
+        if x < self.width && y < self.height {
+            Some((x, y))
+        } else {
+            None
+        }
+    }
+
+    /// Iterates over the positions directly above, below, left and right of `pos` that are inside
+    /// the field.
+    pub fn neighbors4(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(pos, dir))
+    }
+
+    /// Iterates over the positions surrounding `pos`, including diagonally, that are inside the
+    /// field.
+    pub fn neighbors8(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Dir8::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(pos, dir))
+    }
+
+    fn out_of_bounds(&self, (x, y): (usize, usize)) -> ! {
+        panic!(
+            "({x}, {y}) is outside a {}x{} field",
+            self.width, self.height
+        )
+    }
+
     fn idx_to_coords(&self, idx: usize) -> (usize, usize) {
-        (idx % self.width, idx / self.height)
+        (idx % self.width, idx / self.width)
     }
 
     fn get_idx(&self, idx: usize) -> &T {
@@ -105,17 +531,25 @@ impl<T> Field<T> {
     }
 }
 
+impl<T: TryFrom<char>> FromStr for Field<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_with(s, T::try_from)
+    }
+}
+
 pub struct FieldIter<'a, T> {
     field: &'a Field<T>,
     idx: usize,
-    idx_back: usize,
+    idx_end: usize,
 }
 
 impl<'a, T> Iterator for FieldIter<'a, T> {
     type Item = ((usize, usize), &'a T);
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.idx > self.idx_back {
+        if self.idx >= self.idx_end {
             None
         } else {
             let res = (
@@ -130,7 +564,7 @@ impl<'a, T> Iterator for FieldIter<'a, T> {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let remaining = self.idx_back + 1 - self.idx;
+        let remaining = self.idx_end - self.idx;
 
         (remaining, Some(remaining))
     }
@@ -138,17 +572,85 @@ impl<'a, T> Iterator for FieldIter<'a, T> {
 
 impl<'a, T> DoubleEndedIterator for FieldIter<'a, T> {
     fn next_back(&mut self) -> Option<Self::Item> {
-        if self.idx > self.idx_back {
+        if self.idx >= self.idx_end {
             None
         } else {
-            let res = (
-                self.field.idx_to_coords(self.idx_back),
-                self.field.get_idx(self.idx_back),
-            );
+            self.idx_end -= 1;
+
+            Some((
+                self.field.idx_to_coords(self.idx_end),
+                self.field.get_idx(self.idx_end),
+            ))
+        }
+    }
+}
 
-            self.idx_back -= 1;
+pub struct FieldIterMut<'a, T> {
+    iter: Enumerate<IterMut<'a, T>>,
+    width: usize,
+}
 
-            Some(res)
+impl<'a, T> Iterator for FieldIterMut<'a, T> {
+    type Item = ((usize, usize), &'a mut T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (idx, tile) = self.iter.next()?;
+
+        Some(((idx % self.width, idx / self.width), tile))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for FieldIterMut<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (idx, tile) = self.iter.next_back()?;
+
+        Some(((idx % self.width, idx / self.width), tile))
+    }
+}
+
+pub struct FieldRowIterMut<'a, T> {
+    rest: &'a mut [T],
+    width: usize,
+    rows: usize,
+}
+
+impl<'a, T> Iterator for FieldRowIterMut<'a, T> {
+    type Item = &'a mut [T];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rows == 0 {
+            None
+        } else {
+            let (row, rest) = mem::take(&mut self.rest).split_at_mut(self.width);
+
+            self.rest = rest;
+            self.rows -= 1;
+
+            Some(row)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.rows, Some(self.rows))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for FieldRowIterMut<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.rows == 0 {
+            None
+        } else {
+            let rest = mem::take(&mut self.rest);
+            let (rest, row) = rest.split_at_mut(rest.len() - self.width);
+
+            self.rest = rest;
+            self.rows -= 1;
+
+            Some(row)
         }
     }
 }
@@ -156,7 +658,7 @@ impl<'a, T> DoubleEndedIterator for FieldIter<'a, T> {
 pub struct FieldRowIter<'a, T> {
     field: &'a Field<T>,
     y: usize,
-    y_back: usize,
+    y_end: usize,
 }
 
 #[derive(Clone)]
@@ -164,22 +666,17 @@ pub struct FieldRowIterIndividual<'a, T> {
     field: &'a Field<T>,
     y: usize,
     x: usize,
-    x_back: usize,
+    x_end: usize,
 }
 
 impl<'a, T> Iterator for FieldRowIter<'a, T> {
     type Item = FieldRowIterIndividual<'a, T>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.y > self.y_back {
+        if self.y >= self.y_end {
             None
         } else {
-            let res = Some(FieldRowIterIndividual {
-                field: self.field,
-                y: self.y,
-                x: 0,
-                x_back: self.field.width - 1,
-            });
+            let res = Some(self.field.row(self.y));
 
             self.y += 1;
 
@@ -188,31 +685,18 @@ impl<'a, T> Iterator for FieldRowIter<'a, T> {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (self.y_back + 1 - self.y, Some(self.y_back + 1 - self.y))
+        (self.y_end - self.y, Some(self.y_end - self.y))
     }
 }
 
 impl<'a, T> DoubleEndedIterator for FieldRowIter<'a, T> {
     fn next_back(&mut self) -> Option<Self::Item> {
-        if self.y > self.y_back {
+        if self.y >= self.y_end {
             None
         } else {
-            let res = Some(FieldRowIterIndividual {
-                field: self.field,
-                y: self.y_back,
-                x: 0,
-                x_back: self.field.width - 1,
-            });
-
-            match self.y_back.checked_sub(1) {
-                Some(val) => self.y_back = val,
-                None => {
-                    self.y_back = 0;
-                    self.y = 1;
-                }
-            }
+            self.y_end -= 1;
 
-            res
+            Some(self.field.row(self.y_end))
         }
     }
 }
@@ -221,7 +705,7 @@ impl<'a, T> Iterator for FieldRowIterIndividual<'a, T> {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.x > self.x_back {
+        if self.x >= self.x_end {
             None
         } else {
             let res = Some(self.field.get((self.x, self.y)));
@@ -233,26 +717,18 @@ impl<'a, T> Iterator for FieldRowIterIndividual<'a, T> {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (self.x_back + 1 - self.x, Some(self.x_back + 1 - self.x))
+        (self.x_end - self.x, Some(self.x_end - self.x))
     }
 }
 
 impl<'a, T> DoubleEndedIterator for FieldRowIterIndividual<'a, T> {
     fn next_back(&mut self) -> Option<Self::Item> {
-        if self.x > self.x_back {
+        if self.x >= self.x_end {
             None
         } else {
-            let res = Some(self.field.get((self.x_back, self.y)));
+            self.x_end -= 1;
 
-            match self.x_back.checked_sub(1) {
-                Some(val) => self.x_back = val,
-                None => {
-                    self.x_back = 0;
-                    self.x = 1;
-                }
-            }
-
-            res
+            Some(self.field.get((self.x_end, self.y)))
         }
     }
 }
@@ -260,14 +736,14 @@ impl<'a, T> DoubleEndedIterator for FieldRowIterIndividual<'a, T> {
 pub struct FieldColIter<'a, T> {
     field: &'a Field<T>,
     x: usize,
-    x_back: usize,
+    x_end: usize,
 }
 
 #[derive(Clone)]
 pub struct FieldColIterIndividual<'a, T> {
     field: &'a Field<T>,
     y: usize,
-    y_back: usize,
+    y_end: usize,
     x: usize,
 }
 
@@ -287,15 +763,10 @@ impl<'a, T> Iterator for FieldColIter<'a, T> {
     type Item = FieldColIterIndividual<'a, T>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.x > self.x_back {
+        if self.x >= self.x_end {
             None
         } else {
-            let res = Some(FieldColIterIndividual {
-                field: self.field,
-                y: 0,
-                y_back: self.field.height - 1,
-                x: self.x,
-            });
+            let res = Some(self.field.col(self.x));
 
             self.x += 1;
 
@@ -304,31 +775,18 @@ impl<'a, T> Iterator for FieldColIter<'a, T> {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (self.x_back + 1 - self.x, Some(self.x_back + 1 - self.x))
+        (self.x_end - self.x, Some(self.x_end - self.x))
     }
 }
 
 impl<'a, T> DoubleEndedIterator for FieldColIter<'a, T> {
     fn next_back(&mut self) -> Option<Self::Item> {
-        if self.x > self.x_back {
+        if self.x >= self.x_end {
             None
         } else {
-            let res = Some(FieldColIterIndividual {
-                field: self.field,
-                y: 0,
-                y_back: self.field.height - 1,
-                x: self.x_back,
-            });
-
-            match self.x_back.checked_sub(1) {
-                Some(val) => self.x_back = val,
-                None => {
-                    self.x_back = 0;
-                    self.x = 1;
-                }
-            }
+            self.x_end -= 1;
 
-            res
+            Some(self.field.col(self.x_end))
         }
     }
 }
@@ -337,7 +795,7 @@ impl<'a, T> Iterator for FieldColIterIndividual<'a, T> {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.y > self.y_back {
+        if self.y >= self.y_end {
             None
         } else {
             let res = Some(self.field.get((self.x, self.y)));
@@ -349,26 +807,18 @@ impl<'a, T> Iterator for FieldColIterIndividual<'a, T> {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        (self.y_back + 1 - self.y, Some(self.y_back + 1 - self.y))
+        (self.y_end - self.y, Some(self.y_end - self.y))
     }
 }
 
 impl<'a, T> DoubleEndedIterator for FieldColIterIndividual<'a, T> {
     fn next_back(&mut self) -> Option<Self::Item> {
-        if self.y > self.y_back {
+        if self.y >= self.y_end {
             None
         } else {
-            let res = Some(self.field.get((self.x, self.y_back)));
+            self.y_end -= 1;
 
-            match self.y_back.checked_sub(1) {
-                Some(val) => self.y_back = val,
-                None => {
-                    self.y_back = 0;
-                    self.y = 1;
-                }
-            }
-
-            res
+            Some(self.field.get((self.x, self.y_end)))
         }
     }
 }
@@ -378,3 +828,5 @@ impl<'a, T> ExactSizeIterator for FieldColIterIndividual<'a, T> {}
 impl<'a, T> ExactSizeIterator for FieldRowIter<'a, T> {}
 impl<'a, T> ExactSizeIterator for FieldRowIterIndividual<'a, T> {}
 impl<'a, T> ExactSizeIterator for FieldIter<'a, T> {}
+impl<'a, T> ExactSizeIterator for FieldIterMut<'a, T> {}
+impl<'a, T> ExactSizeIterator for FieldRowIterMut<'a, T> {}
@@ -0,0 +1,295 @@
+use std::{
+    env,
+    error::Error,
+    fmt::{Debug, Display},
+    fs::{self, File},
+    io::{self, BufRead, BufReader, Read},
+    path::{Path, PathBuf},
+};
+
+/// The year of Advent of Code these solutions are for.
+pub const YEAR: u16 = 2023;
+
+/// Where a day's puzzle input is read from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Source {
+    /// A file given on the command line.
+    Path(PathBuf),
+    /// Standard input, asked for with `-` on the command line.
+    Stdin,
+    /// `inputs/day-NN.txt` at the root of the workspace, used when no path is given.
+    Default { day: u8 },
+}
+
+impl Source {
+    /// Picks where to read the input for `day` from, given the command line argument naming it
+    /// if there was one.
+    pub fn from_arg(day: u8, arg: Option<&str>) -> Self {
+        match arg {
+            Some("-") => Source::Stdin,
+            Some(path) => Source::Path(PathBuf::from(path)),
+            None => Source::Default { day },
+        }
+    }
+
+    /// The file this source reads from, or `None` for standard input.
+    pub fn path(&self) -> Option<PathBuf> {
+        match self {
+            Source::Path(path) => Some(path.clone()),
+            Source::Stdin => None,
+            Source::Default { day } => Some(default_path(*day)),
+        }
+    }
+
+    /// Opens the input for reading a piece at a time.
+    pub fn open(&self) -> Result<Box<dyn BufRead>, InputError> {
+        let Some(path) = self.path() else {
+            return Ok(Box::new(io::stdin().lock()));
+        };
+
+        // Inputs fetched into the cache are used when there isn't one at the usual path
+        let cached = match self {
+            Source::Default { day } => Some(Cache::default().path(YEAR, *day)),
+            _ => None,
+        };
+        let path = match cached {
+            Some(cached) if !path.exists() && cached.exists() => cached,
+            _ => path,
+        };
+
+        match File::open(&path) {
+            Ok(file) => Ok(Box::new(BufReader::new(file))),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Err(InputError::Missing(path)),
+            Err(err) => Err(InputError::Unreadable(Some(path), err)),
+        }
+    }
+
+    /// Reads the whole input.
+    pub fn read(&self) -> Result<String, InputError> {
+        let mut input = String::new();
+
+        self.open()?
+            .read_to_string(&mut input)
+            .map_err(|err| InputError::Unreadable(self.path(), err))?;
+
+        Ok(input)
+    }
+}
+
+/// Reads the input for `day` from the path given as the first command line argument, standard
+/// input if that is `-`, or the workspace's `inputs` directory if there is no argument.
+pub fn from_args(day: u8) -> Result<String, InputError> {
+    Source::from_arg(day, env::args().nth(1).as_deref()).read()
+}
+
+/// The root of the workspace this was built in, so inputs are found wherever a day is run from.
+pub fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("common is inside the workspace")
+}
+
+/// Where the input for `day` lives when no other path is given.
+pub fn default_path(day: u8) -> PathBuf {
+    workspace_root()
+        .join("inputs")
+        .join(format!("day-{day:02}.txt"))
+}
+
+/// Somewhere puzzle inputs can be found.
+pub trait InputProvider {
+    fn input(&self, year: u16, day: u8) -> Result<String, InputError>;
+}
+
+/// A directory of inputs saved as `<year>/day-NN.txt`. By default this is the workspace's
+/// `inputs` directory.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.dir
+            .join(year.to_string())
+            .join(format!("day-{day:02}.txt"))
+    }
+
+    pub fn store(&self, year: u16, day: u8, input: &str) -> Result<(), InputError> {
+        let path = self.path(year, day);
+
+        path.parent()
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|()| fs::write(&path, input))
+            .map_err(|err| InputError::Unwritable(path, err))
+    }
+}
+
+impl Default for Cache {
+    fn default() -> Self {
+        Self::new(workspace_root().join("inputs"))
+    }
+}
+
+impl InputProvider for Cache {
+    fn input(&self, year: u16, day: u8) -> Result<String, InputError> {
+        Source::Path(self.path(year, day)).read()
+    }
+}
+
+/// The session cookie of a logged in Advent of Code account, which is needed to download inputs.
+/// It is never printed, even when debug formatted.
+#[derive(Clone)]
+pub struct Session(String);
+
+impl Session {
+    /// The environment variable the session cookie is read from.
+    pub const VAR: &'static str = "AOC_SESSION";
+
+    pub fn new(token: impl Into<String>) -> Self {
+        Self(token.into())
+    }
+
+    pub fn from_env() -> Result<Self, InputError> {
+        Self::from_var(env::var(Self::VAR).ok().as_deref())
+    }
+
+    /// Reads the session cookie from `value`, the contents of [`Session::VAR`] if it is set.
+    pub fn from_var(value: Option<&str>) -> Result<Self, InputError> {
+        match value.map(str::trim) {
+            Some(token) if !token.is_empty() => Ok(Self(token.to_string())),
+            _ => Err(InputError::NoSession),
+        }
+    }
+}
+
+impl Debug for Session {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Session(<redacted>)")
+    }
+}
+
+/// Downloads inputs from the Advent of Code website, or any server laid out the same way.
+#[derive(Clone, Debug)]
+pub struct Http {
+    base_url: String,
+    session: Session,
+}
+
+impl Http {
+    pub const BASE_URL: &'static str = "https://adventofcode.com";
+
+    pub fn new(session: Session) -> Self {
+        Self {
+            base_url: Self::BASE_URL.to_string(),
+            session,
+        }
+    }
+
+    /// Downloads from `base_url` instead of the Advent of Code website, such as a local server in
+    /// tests.
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+}
+
+impl InputProvider for Http {
+    fn input(&self, year: u16, day: u8) -> Result<String, InputError> {
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+
+        // The errors only ever mention the url, so the session can't end up in them
+        let fetch_error = |reason: String| InputError::Fetch {
+            url: url.clone(),
+            reason,
+        };
+
+        let response = ureq::get(&url)
+            .set("Cookie", &format!("session={}", self.session.0))
+            .set("User-Agent", "github.com/BlueZeeKing/aoc-2023")
+            .call()
+            .map_err(|err| match err {
+                ureq::Error::Status(status, _) => fetch_error(format!("status {status}")),
+                ureq::Error::Transport(err) => fetch_error(err.kind().to_string()),
+            })?;
+
+        response
+            .into_string()
+            .map_err(|err| fetch_error(err.to_string()))
+    }
+}
+
+/// Reads inputs from a cache, getting any that are missing from another provider and saving them
+/// so they are only downloaded once.
+#[derive(Clone, Debug)]
+pub struct Cached<P> {
+    cache: Cache,
+    provider: P,
+}
+
+impl<P> Cached<P> {
+    pub fn new(cache: Cache, provider: P) -> Self {
+        Self { cache, provider }
+    }
+}
+
+impl<P: InputProvider> InputProvider for Cached<P> {
+    fn input(&self, year: u16, day: u8) -> Result<String, InputError> {
+        match self.cache.input(year, day) {
+            Err(InputError::Missing(_)) => {
+                let input = self.provider.input(year, day)?;
+                self.cache.store(year, day, &input)?;
+                Ok(input)
+            }
+            result => result,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    /// There is no file at the path.
+    Missing(PathBuf),
+    /// The file (or standard input, with no path) exists but couldn't be read.
+    Unreadable(Option<PathBuf>, io::Error),
+    /// The file couldn't be saved, such as when storing a download in the cache.
+    Unwritable(PathBuf, io::Error),
+    /// Downloading needs a session cookie, but there isn't one in the environment.
+    NoSession,
+    /// The input couldn't be downloaded.
+    Fetch { url: String, reason: String },
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::Missing(path) => write!(f, "No input found at {}", path.display()),
+            InputError::Unreadable(Some(path), err) => {
+                write!(f, "Could not read {}: {err}", path.display())
+            }
+            InputError::Unreadable(None, err) => write!(f, "Could not read standard input: {err}"),
+            InputError::Unwritable(path, err) => {
+                write!(f, "Could not write {}: {err}", path.display())
+            }
+            InputError::NoSession => write!(
+                f,
+                "Set {} to your adventofcode.com session cookie to download inputs",
+                Session::VAR
+            ),
+            InputError::Fetch { url, reason } => write!(f, "Could not download {url}: {reason}"),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::Unreadable(_, err) | InputError::Unwritable(_, err) => Some(err),
+            _ => None,
+        }
+    }
+}
@@ -1,6 +1,12 @@
 use std::fmt::Debug;
 
 pub mod field;
+pub mod input;
+pub mod parse;
+pub mod search;
+pub mod solution;
+pub mod span;
+pub mod sparse_field;
 
 pub trait IterExt: Iterator + Sized {
     fn consume(self) {
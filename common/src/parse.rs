@@ -0,0 +1,136 @@
+use std::{error::Error, fmt::Display, str::FromStr};
+
+/// An error encountered while parsing puzzle input, along with where it happened.
+///
+/// Lines and columns both start at 1, and columns count characters rather than bytes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    line: usize,
+    column: usize,
+    text: String,
+    reason: String,
+}
+
+impl ParseError {
+    pub fn new(
+        line: usize,
+        column: usize,
+        text: impl Into<String>,
+        reason: impl Into<String>,
+    ) -> Self {
+        Self {
+            line,
+            column,
+            text: text.into(),
+            reason: reason.into(),
+        }
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// The offending text, which is empty if something was missing rather than malformed.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+
+    /// Moves the error to `line`, for errors produced by parsing a single line on its own (such
+    /// as through [`FromStr`]), which always report line 1.
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.line = line;
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.reason
+        )?;
+
+        if !self.text.is_empty() {
+            write!(f, " {:?}", self.text)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for ParseError {}
+
+/// A single line of puzzle input along with its line number, used to point errors at the part of
+/// the line they came from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Line<'a> {
+    number: usize,
+    text: &'a str,
+}
+
+/// Splits `input` into its lines, numbering them from 1.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(idx, text)| Line::new(idx + 1, text))
+}
+
+impl<'a> Line<'a> {
+    pub fn new(number: usize, text: &'a str) -> Self {
+        Self { number, text }
+    }
+
+    pub fn number(&self) -> usize {
+        self.number
+    }
+
+    pub fn text(&self) -> &'a str {
+        self.text
+    }
+
+    /// Creates an error pointing at `part`, which should be a slice of this line. Anything else is
+    /// reported at the start of the line.
+    pub fn error(&self, part: &str, reason: impl Into<String>) -> ParseError {
+        ParseError::new(self.number, self.column_of(part), part, reason)
+    }
+
+    /// Parses `part`, a slice of this line, reporting it as an invalid `what` if it can't be.
+    pub fn parse<T: FromStr>(&self, part: &str, what: &str) -> Result<T, ParseError> {
+        part.parse()
+            .map_err(|_| self.error(part, format!("invalid {what}")))
+    }
+
+    /// Unwraps a part of the line that should be there, reporting the end of the line as missing
+    /// a `what` if it isn't.
+    pub fn expect<T>(&self, value: Option<T>, what: &str) -> Result<T, ParseError> {
+        value.ok_or_else(|| {
+            ParseError::new(
+                self.number,
+                self.text.chars().count() + 1,
+                "",
+                format!("missing {what}"),
+            )
+        })
+    }
+
+    fn column_of(&self, part: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let offset = (part.as_ptr() as usize).wrapping_sub(start);
+
+        if offset <= self.text.len() && self.text.is_char_boundary(offset) {
+            self.text[..offset].chars().count() + 1
+        } else {
+            1
+        }
+    }
+}
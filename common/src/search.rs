@@ -0,0 +1,200 @@
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, hash_map::RandomState, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+};
+
+use crate::field::Field;
+
+/// A graph to search, where each state knows which states it leads to.
+pub trait Neighbors {
+    type State: Clone + Eq + Hash;
+
+    /// The states reachable in one step from `state`, along with the cost of taking that step.
+    /// [`bfs`] ignores the costs and counts every step as 1.
+    fn neighbors(&self, state: &Self::State) -> impl Iterator<Item = (Self::State, u64)>;
+}
+
+/// A route found by a search, from the start state to the goal inclusive.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path<S> {
+    pub cost: u64,
+    pub states: Vec<S>,
+}
+
+/// Finds the route to the nearest state matching `is_goal` by number of steps.
+pub fn bfs<G: Neighbors>(
+    graph: &G,
+    start: G::State,
+    mut is_goal: impl FnMut(&G::State) -> bool,
+) -> Option<Path<G::State>> {
+    let mut visited = Visited::new(start);
+    let mut queue = VecDeque::from([(0, 0)]);
+
+    while let Some((idx, steps)) = queue.pop_front() {
+        let state = visited.states[idx].clone();
+
+        if is_goal(&state) {
+            return Some(visited.path(idx, steps));
+        }
+
+        for (next, _) in graph.neighbors(&state) {
+            if let Some(next_idx) = visited.improve(next, idx, steps + 1) {
+                queue.push_back((next_idx, steps + 1));
+            }
+        }
+    }
+
+    None
+}
+
+/// Finds every state reachable from `start` along with the fewest steps it takes to get there.
+pub fn reachable<G: Neighbors>(graph: &G, start: G::State) -> HashMap<G::State, u64, RandomState> {
+    let mut visited = Visited::new(start);
+    let mut queue = VecDeque::from([(0, 0)]);
+
+    while let Some((idx, steps)) = queue.pop_front() {
+        let state = visited.states[idx].clone();
+
+        for (next, _) in graph.neighbors(&state) {
+            if let Some(next_idx) = visited.improve(next, idx, steps + 1) {
+                queue.push_back((next_idx, steps + 1));
+            }
+        }
+    }
+
+    visited.states.into_iter().zip(visited.costs).collect()
+}
+
+/// Finds the cheapest route to a state matching `is_goal`.
+pub fn dijkstra<G: Neighbors>(
+    graph: &G,
+    start: G::State,
+    is_goal: impl FnMut(&G::State) -> bool,
+) -> Option<Path<G::State>> {
+    astar(graph, start, is_goal, |_| 0)
+}
+
+/// Finds the cheapest route to a state matching `is_goal`, exploring the states `heuristic`
+/// estimates are closest to a goal first.
+///
+/// The heuristic must never overestimate the remaining cost, or the route found may not be the
+/// cheapest.
+pub fn astar<G: Neighbors>(
+    graph: &G,
+    start: G::State,
+    mut is_goal: impl FnMut(&G::State) -> bool,
+    mut heuristic: impl FnMut(&G::State) -> u64,
+) -> Option<Path<G::State>> {
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), 0, 0))]);
+    let mut visited = Visited::new(start);
+
+    while let Some(Reverse((_, cost, idx))) = queue.pop() {
+        if cost > visited.costs[idx] {
+            // A cheaper route to this state was found after this one was queued
+            continue;
+        }
+
+        let state = visited.states[idx].clone();
+
+        if is_goal(&state) {
+            return Some(visited.path(idx, cost));
+        }
+
+        for (next, step_cost) in graph.neighbors(&state) {
+            let next_cost = cost + step_cost;
+
+            if let Some(next_idx) = visited.improve(next, idx, next_cost) {
+                let estimate = next_cost + heuristic(&visited.states[next_idx]);
+                queue.push(Reverse((estimate, next_cost, next_idx)));
+            }
+        }
+    }
+
+    None
+}
+
+/// Every state seen so far, indexed in the order they were found so the queues don't need the
+/// states themselves to be ordered.
+struct Visited<S> {
+    states: Vec<S>,
+    costs: Vec<u64>,
+    parents: Vec<Option<usize>>,
+    indices: HashMap<S, usize, RandomState>,
+}
+
+impl<S: Clone + Eq + Hash> Visited<S> {
+    fn new(start: S) -> Self {
+        Self {
+            indices: HashMap::from_iter([(start.clone(), 0)]),
+            states: vec![start],
+            costs: vec![0],
+            parents: vec![None],
+        }
+    }
+
+    /// Records reaching `state` from `parent` for `cost`, returning its index if that is cheaper
+    /// than any route to it seen before.
+    fn improve(&mut self, state: S, parent: usize, cost: u64) -> Option<usize> {
+        match self.indices.entry(state) {
+            Entry::Occupied(entry) => {
+                let idx = *entry.get();
+
+                if cost >= self.costs[idx] {
+                    return None;
+                }
+
+                self.costs[idx] = cost;
+                self.parents[idx] = Some(parent);
+
+                Some(idx)
+            }
+            Entry::Vacant(entry) => {
+                let idx = self.states.len();
+
+                self.states.push(entry.key().clone());
+                self.costs.push(cost);
+                self.parents.push(Some(parent));
+                entry.insert(idx);
+
+                Some(idx)
+            }
+        }
+    }
+
+    fn path(&self, mut idx: usize, cost: u64) -> Path<S> {
+        let mut states = vec![self.states[idx].clone()];
+
+        while let Some(parent) = self.parents[idx] {
+            states.push(self.states[parent].clone());
+            idx = parent;
+        }
+
+        states.reverse();
+
+        Path { cost, states }
+    }
+}
+
+/// Searches a [`Field`] by moving up, down, left or right, where `cost` gives the cost of moving
+/// onto a tile or `None` if it is a wall.
+pub struct FieldGraph<'a, T, F> {
+    field: &'a Field<T>,
+    cost: F,
+}
+
+impl<'a, T, F: Fn(&T) -> Option<u64>> FieldGraph<'a, T, F> {
+    pub fn new(field: &'a Field<T>, cost: F) -> Self {
+        Self { field, cost }
+    }
+}
+
+impl<'a, T, F: Fn(&T) -> Option<u64>> Neighbors for FieldGraph<'a, T, F> {
+    type State = (usize, usize);
+
+    fn neighbors(&self, state: &(usize, usize)) -> impl Iterator<Item = ((usize, usize), u64)> {
+        self.field
+            .neighbors4(*state)
+            .filter_map(|pos| Some((pos, (self.cost)(self.field.get(pos))?)))
+    }
+}
@@ -0,0 +1,59 @@
+use std::{fmt::Display, process::ExitCode};
+
+use crate::{input, parse::ParseError};
+
+/// A solver for a single day's puzzle.
+///
+/// Every `day-N` crate implements this for a unit struct (`day_1::Day1`, ...) so the `aoc`
+/// runner can dispatch to any day without knowing how it parses or solves its input. The input is
+/// parsed once with [`Solution::parse`] and then shared between both parts, so malformed input is
+/// reported as a [`ParseError`] before either part runs.
+pub trait Solution {
+    type Input;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input) -> Self::Part1;
+
+    fn part2(input: &Self::Input) -> Self::Part2;
+}
+
+/// One of the two parts of a puzzle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+/// Parses `input` and prints the answer to `part`, or both parts if it is `None`.
+pub fn run<S: Solution>(input: &str, part: Option<Part>) -> Result<(), String> {
+    let input = S::parse(input).map_err(|err| format!("Invalid input: {err}"))?;
+
+    if part != Some(Part::Two) {
+        println!("Part 1: {}", S::part1(&input));
+    }
+
+    if part != Some(Part::One) {
+        println!("Part 2: {}", S::part2(&input));
+    }
+
+    Ok(())
+}
+
+/// The whole of a day's binary: reads the input for `day` as [`input::from_args`] does and prints
+/// both parts, reporting anything that goes wrong on stderr.
+pub fn main<S: Solution>(day: u8) -> ExitCode {
+    let result = input::from_args(day)
+        .map_err(|err| err.to_string())
+        .and_then(|input| run::<S>(&input, None));
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use std::ops::RangeInclusive;
+
+use crate::field::Field;
+
+/// Iterates over the positions surrounding a run of cells in a single row, including diagonally,
+/// that are inside a field. The run itself is skipped.
+#[derive(Clone, Debug)]
+pub struct SurroundingIter {
+    cols: RangeInclusive<usize>,
+    row: usize,
+    xs: RangeInclusive<usize>,
+    ys: RangeInclusive<usize>,
+    x: usize,
+    y: usize,
+}
+
+impl SurroundingIter {
+    /// Surrounds `cols` of `row` in a field `width` tiles wide and `height` tiles tall.
+    pub fn new(cols: RangeInclusive<usize>, row: usize, width: usize, height: usize) -> Self {
+        let xs = around(*cols.start(), *cols.end(), width);
+        let ys = around(row, row, height);
+
+        Self {
+            x: *xs.start(),
+            y: *ys.start(),
+            cols,
+            row,
+            xs,
+            ys,
+        }
+    }
+}
+
+impl Iterator for SurroundingIter {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // A run that is off the field can leave nothing around it to visit
+        if self.xs.is_empty() || self.ys.is_empty() {
+            return None;
+        }
+
+        while self.ys.contains(&self.y) {
+            let pos = (self.x, self.y);
+
+            if self.x < *self.xs.end() {
+                self.x += 1;
+            } else {
+                self.x = *self.xs.start();
+                self.y += 1;
+            }
+
+            if pos.1 != self.row || !self.cols.contains(&pos.0) {
+                return Some(pos);
+            }
+        }
+
+        None
+    }
+}
+
+/// The indices from one before `start` to one after `end`, cut down to those inside `0..len`.
+fn around(start: usize, end: usize, len: usize) -> RangeInclusive<usize> {
+    match len.checked_sub(1) {
+        Some(last) => start.saturating_sub(1)..=end.saturating_add(1).min(last),
+        None => RangeInclusive::new(1, 0),
+    }
+}
+
+/// Joins up digits that are next to each other in the same row into numbers, yielding each
+/// number along with the columns and row it spans. Digits should come in reading order.
+#[derive(Clone, Debug)]
+pub struct GroupingIter<I: Iterator<Item = ((usize, usize), u32)>> {
+    iter: I,
+    prev: Option<((usize, usize), u32)>,
+}
+
+impl<I: Iterator<Item = ((usize, usize), u32)>> Iterator for GroupingIter<I> {
+    type Item = ((RangeInclusive<usize>, usize), u32);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let ((mut x, y), mut val) = self.prev.take().or_else(|| self.iter.next())?;
+        let first_x = x;
+
+        for ((new_x, new_y), new_val) in self.iter.by_ref() {
+            // The last digit of a row and the first of the next are never part of one number, even
+            // though they come one after the other
+            if new_y == y && new_x == x + 1 {
+                x = new_x;
+                val = val * 10 + new_val;
+            } else {
+                self.prev = Some(((new_x, new_y), new_val));
+                break;
+            }
+        }
+
+        Some(((first_x..=x, y), val))
+    }
+}
+
+pub trait IntoGroupingIter: Iterator<Item = ((usize, usize), u32)> + Sized {
+    fn grouping_iter(self) -> GroupingIter<Self> {
+        GroupingIter {
+            iter: self,
+            prev: None,
+        }
+    }
+}
+
+impl<I: Iterator<Item = ((usize, usize), u32)> + Sized> IntoGroupingIter for I {}
+
+impl<T> Field<T> {
+    /// Iterates over the positions surrounding `cols` of `row` that are inside the field.
+    pub fn surrounding(&self, cols: RangeInclusive<usize>, row: usize) -> SurroundingIter {
+        SurroundingIter::new(cols, row, self.num_cols(), self.num_rows())
+    }
+
+    /// Iterates over every number written in the field, where `digit` gives the value of the
+    /// tiles that are digits. Numbers don't carry on from the end of one row to the next.
+    pub fn numbers<'a>(
+        &'a self,
+        mut digit: impl FnMut(&T) -> Option<u32> + 'a,
+    ) -> impl Iterator<Item = ((RangeInclusive<usize>, usize), u32)> + 'a {
+        self.iter()
+            .filter_map(move |(pos, tile)| Some((pos, digit(tile)?)))
+            .grouping_iter()
+    }
+}
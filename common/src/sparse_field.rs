@@ -0,0 +1,228 @@
+use std::{
+    collections::{hash_map::RandomState, HashMap},
+    ops::RangeInclusive,
+};
+
+use crate::field::Field;
+
+/// A grid with no fixed size where coordinates can be negative, storing only the tiles that have
+/// been set. Every other tile reads as the default value.
+#[derive(Clone, Debug)]
+pub struct SparseField<T> {
+    tiles: HashMap<(i64, i64), T, RandomState>,
+    default: T,
+    /// The area of the dense field this was made from, which stays inside the bounds even where
+    /// none of its tiles are set, including after they are removed.
+    extent: Option<Bounds>,
+}
+
+/// The smallest rectangle containing every set tile of a [`SparseField`], along with the whole of
+/// the field it was made from if it came from [`SparseField::from_field`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Bounds {
+    pub x: RangeInclusive<i64>,
+    pub y: RangeInclusive<i64>,
+}
+
+impl Bounds {
+    pub fn width(&self) -> usize {
+        (self.x.end() - self.x.start() + 1) as usize
+    }
+
+    pub fn height(&self) -> usize {
+        (self.y.end() - self.y.start() + 1) as usize
+    }
+
+    pub fn contains(&self, (x, y): (i64, i64)) -> bool {
+        self.x.contains(&x) && self.y.contains(&y)
+    }
+}
+
+impl<T> SparseField<T> {
+    pub fn new(default: T) -> Self {
+        Self {
+            tiles: HashMap::default(),
+            default,
+            extent: None,
+        }
+    }
+
+    /// Copies every tile of `field` that isn't `default`, with the top left tile at `(0, 0)`.
+    ///
+    /// The bounds always cover the whole of `field`, even if the tiles around its edges are
+    /// `default`, so converting back with [`SparseField::to_field`] gives a field of the same size.
+    /// They never shrink below it, even once every tile has been removed, but setting tiles outside
+    /// of it still grows them.
+    pub fn from_field(field: &Field<T>, default: T) -> Self
+    where
+        T: Clone + PartialEq,
+    {
+        let tiles = field
+            .iter()
+            .filter(|(_, tile)| **tile != default)
+            .map(|((x, y), tile)| ((x as i64, y as i64), tile.clone()))
+            .collect();
+
+        let extent = (field.num_cols() > 0 && field.num_rows() > 0).then(|| Bounds {
+            x: 0..=field.num_cols() as i64 - 1,
+            y: 0..=field.num_rows() as i64 - 1,
+        });
+
+        Self {
+            tiles,
+            default,
+            extent,
+        }
+    }
+
+    pub fn default_value(&self) -> &T {
+        &self.default
+    }
+
+    pub fn get(&self, pos: (i64, i64)) -> &T {
+        self.tiles.get(&pos).unwrap_or(&self.default)
+    }
+
+    /// Gets the tile at `pos`, setting it to the default value first if it isn't already set.
+    ///
+    /// The tile counts as set afterwards even if it is left as the default value, so this can grow
+    /// the bounds. Use [`SparseField::get`] to look at a tile without setting it.
+    pub fn get_mut(&mut self, pos: (i64, i64)) -> &mut T
+    where
+        T: Clone,
+    {
+        self.tiles
+            .entry(pos)
+            .or_insert_with(|| self.default.clone())
+    }
+
+    /// Sets the tile at `pos`, returning the previous value if it had been set.
+    pub fn set(&mut self, pos: (i64, i64), value: T) -> Option<T> {
+        self.tiles.insert(pos, value)
+    }
+
+    /// Resets the tile at `pos` to the default value, returning the previous value if it had been
+    /// set. This can shrink the bounds, though never below the field this was made from.
+    pub fn remove(&mut self, pos: (i64, i64)) -> Option<T> {
+        self.tiles.remove(&pos)
+    }
+
+    pub fn is_set(&self, pos: (i64, i64)) -> bool {
+        self.tiles.contains_key(&pos)
+    }
+
+    /// The number of tiles that have been set.
+    pub fn len(&self) -> usize {
+        self.tiles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tiles.is_empty()
+    }
+
+    /// Iterates over the tiles that have been set, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = ((i64, i64), &T)> + '_ {
+        self.tiles.iter().map(|(pos, tile)| (*pos, tile))
+    }
+
+    /// Finds the smallest rectangle containing every set tile (and the whole of the field this was
+    /// made from, if it came from [`SparseField::from_field`]), or `None` if there is nothing in it.
+    ///
+    /// This looks at every set tile, so avoid calling it in a loop.
+    pub fn bounds(&self) -> Option<Bounds> {
+        let corners = self.extent.iter().flat_map(|extent| {
+            [
+                (*extent.x.start(), *extent.y.start()),
+                (*extent.x.end(), *extent.y.end()),
+            ]
+        });
+        let mut positions = self.tiles.keys().copied().chain(corners);
+        let (x, y) = positions.next()?;
+
+        let (min_x, max_x, min_y, max_y) =
+            positions.fold((x, x, y, y), |(min_x, max_x, min_y, max_y), (x, y)| {
+                (min_x.min(x), max_x.max(x), min_y.min(y), max_y.max(y))
+            });
+
+        Some(Bounds {
+            x: min_x..=max_x,
+            y: min_y..=max_y,
+        })
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.bounds().map_or(0, |bounds| bounds.height())
+    }
+
+    pub fn num_cols(&self) -> usize {
+        self.bounds().map_or(0, |bounds| bounds.width())
+    }
+
+    /// Iterates over each row of the bounding box from top to bottom, with unset tiles reading as
+    /// the default value.
+    pub fn rows(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &T> + '_> + '_ {
+        let (xs, ys) = self.ranges();
+
+        ys.map(move |y| xs.clone().map(move |x| self.get((x, y))))
+    }
+
+    /// Iterates over row `y` across the width of the bounding box.
+    pub fn row(&self, y: i64) -> impl DoubleEndedIterator<Item = &T> + '_ {
+        let (xs, _) = self.ranges();
+
+        xs.map(move |x| self.get((x, y)))
+    }
+
+    /// Iterates over each column of the bounding box from left to right, with unset tiles reading
+    /// as the default value.
+    pub fn cols(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &T> + '_> + '_ {
+        let (xs, ys) = self.ranges();
+
+        xs.map(move |x| ys.clone().map(move |y| self.get((x, y))))
+    }
+
+    /// Iterates over column `x` across the height of the bounding box.
+    pub fn col(&self, x: i64) -> impl DoubleEndedIterator<Item = &T> + '_ {
+        let (_, ys) = self.ranges();
+
+        ys.map(move |y| self.get((x, y)))
+    }
+
+    /// Copies the bounding box into a dense field, so the tile at `(x, y)` in the field is the one
+    /// at `(x + min_x, y + min_y)` here.
+    pub fn to_field(&self) -> Field<T>
+    where
+        T: Clone,
+    {
+        let Some(bounds) = self.bounds() else {
+            return Field::new(0, 0, Vec::new());
+        };
+
+        let tiles = bounds
+            .y
+            .clone()
+            .flat_map(|y| bounds.x.clone().map(move |x| self.get((x, y)).clone()))
+            .collect();
+
+        Field::new(bounds.height(), bounds.width(), tiles)
+    }
+
+    fn ranges(&self) -> (RangeInclusive<i64>, RangeInclusive<i64>) {
+        match self.bounds() {
+            Some(bounds) => (bounds.x, bounds.y),
+            // Nothing is set, so there is nothing to iterate over
+            #[allow(clippy::reversed_empty_ranges)]
+            None => (1..=0, 1..=0),
+        }
+    }
+}
+
+impl<T: Clone> From<&SparseField<T>> for Field<T> {
+    fn from(field: &SparseField<T>) -> Self {
+        field.to_field()
+    }
+}
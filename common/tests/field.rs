@@ -0,0 +1,193 @@
+use common::{
+    field::{Dir8, Direction, Field, TileError},
+    parse::ParseError,
+};
+
+fn parse_bits(input: &str) -> Result<Field<bool>, ParseError> {
+    Field::from_str_with(input, |char| match char {
+        '#' => Ok(true),
+        '.' => Ok(false),
+        char => Err(TileError(char)),
+    })
+}
+
+#[test]
+fn from_str_with_reads_rows() {
+    let field = parse_bits("#..\n.#.\n").unwrap();
+
+    assert_eq!((field.num_cols(), field.num_rows()), (3, 2));
+    assert!(*field.get((0, 0)));
+    assert!(*field.get((1, 1)));
+    assert!(!*field.get((2, 1)));
+}
+
+#[test]
+fn from_str_with_reports_unknown_tiles() {
+    let err = parse_bits("#..\n.x.\n").err().unwrap();
+
+    assert_eq!((err.line(), err.column(), err.text()), (2, 2, "x"));
+}
+
+#[test]
+fn from_str_with_reports_short_rows() {
+    let err = parse_bits("#..\n.#\n...\n").err().unwrap();
+
+    assert_eq!((err.line(), err.column(), err.text()), (2, 3, ""));
+    assert_eq!(
+        err.to_string(),
+        "line 2, column 3: row shorter than 3 tiles"
+    );
+}
+
+#[test]
+fn from_str_with_reports_long_rows() {
+    let err = parse_bits("#..\n.#..#\n").err().unwrap();
+
+    assert_eq!((err.line(), err.column(), err.text()), (2, 4, ".#"));
+}
+
+#[test]
+fn from_str_uses_try_from_char() {
+    let field: Field<char> = "ab\ncd".parse().unwrap();
+
+    assert_eq!(field.row(1).collect::<String>(), "cd");
+}
+
+#[test]
+fn step_stays_inside_field() {
+    let field: Field<char> = "abc\ndef".parse().unwrap();
+
+    assert_eq!(field.step((0, 0), Direction::Right), Some((1, 0)));
+    assert_eq!(field.step((0, 0), Direction::Up), None);
+    assert_eq!(field.step((0, 0), Direction::Left), None);
+    assert_eq!(field.step((2, 1), Direction::Right), None);
+    assert_eq!(field.step((2, 1), Direction::Down), None);
+    assert_eq!(field.step((2, 0), Dir8::DownLeft), Some((1, 1)));
+    assert_eq!(field.step((2, 0), Dir8::UpLeft), None);
+}
+
+#[test]
+fn neighbors_are_clipped_to_field() {
+    let field: Field<char> = "abc\ndef\nghi".parse().unwrap();
+
+    let mut corner = field.neighbors4((0, 0)).collect::<Vec<_>>();
+    corner.sort();
+    assert_eq!(corner, [(0, 1), (1, 0)]);
+
+    assert_eq!(field.neighbors4((1, 1)).count(), 4);
+    assert_eq!(field.neighbors8((1, 1)).count(), 8);
+    assert_eq!(field.neighbors8((2, 2)).count(), 3);
+    assert_eq!(field.neighbors8((1, 0)).count(), 5);
+}
+
+#[test]
+fn empty_fields_have_no_tiles() {
+    for (width, height) in [(0, 0), (3, 0), (0, 3)] {
+        let field = Field::<char>::new(height, width, Vec::new());
+
+        assert_eq!(field.iter().count(), 0);
+        assert_eq!(field.iter().next_back(), None);
+        assert_eq!(field.rows().count(), height);
+        assert_eq!(field.cols().count(), width);
+        assert!(field.rows().all(|row| row.count() == 0));
+        assert!(field.cols().all(|col| col.count() == 0));
+    }
+}
+
+#[test]
+fn empty_input_parses_to_empty_field() {
+    let field: Field<char> = "".parse().unwrap();
+
+    assert_eq!((field.num_cols(), field.num_rows()), (0, 0));
+    assert_eq!(field.iter().len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "a 3x2 field needs 6 tiles but was given 5")]
+fn new_checks_tile_count() {
+    Field::new(2, 3, vec!['.'; 5]);
+}
+
+#[test]
+fn map_fill_and_swap() {
+    let mut field: Field<char> = "ab\ncd".parse().unwrap();
+
+    field.swap((0, 0), (1, 1));
+    assert_eq!(field.row(0).collect::<String>(), "db");
+    assert_eq!(field.row(1).collect::<String>(), "ca");
+
+    let upper = field.map(|char| char.to_ascii_uppercase());
+    assert_eq!(upper.row(0).collect::<String>(), "DB");
+
+    field.fill('.');
+    assert!(field.iter().all(|(_, char)| *char == '.'));
+}
+
+#[test]
+fn try_get_rejects_positions_outside_field() {
+    let mut field: Field<char> = "abc\ndef".parse().unwrap();
+
+    assert_eq!(field.try_get((2, 1)), Some(&'f'));
+    assert_eq!(field.try_get((3, 0)), None);
+    assert_eq!(field.try_get((0, 2)), None);
+
+    *field.try_get_mut((1, 0)).unwrap() = 'B';
+    assert_eq!(field.get((1, 0)), &'B');
+    assert!(field.try_get_mut((3, 1)).is_none());
+}
+
+#[test]
+#[should_panic(expected = "(3, 0) is outside a 3x2 field")]
+fn get_does_not_wrap_into_next_row() {
+    let field: Field<char> = "abc\ndef".parse().unwrap();
+
+    field.get((3, 0));
+}
+
+#[test]
+#[should_panic(expected = "(3, 0) is outside a 3x2 field")]
+fn swap_does_not_wrap_into_next_row() {
+    let mut field: Field<char> = "abc\ndef".parse().unwrap();
+
+    field.swap((0, 1), (3, 0));
+}
+
+#[test]
+fn signed_and_wrapping_accessors() {
+    let field: Field<char> = "abc\ndef".parse().unwrap();
+
+    assert_eq!(field.get_signed((1, 1)), Some(&'e'));
+    assert_eq!(field.get_signed((-1, 0)), None);
+    assert_eq!(field.get_signed((0, -1)), None);
+    assert_eq!(field.get_signed((3, 0)), None);
+
+    assert_eq!(field.get_wrapping((-1, 0)), &'c');
+    assert_eq!(field.get_wrapping((3, 0)), &'a');
+    assert_eq!(field.get_wrapping((1, -1)), &'e');
+    assert_eq!(field.get_wrapping((-4, 5)), &'f');
+}
+
+#[test]
+fn empty_rows_cols_and_positions() {
+    let field = parse_bits("#...\n....\n..#.\n").unwrap();
+
+    assert_eq!(field.empty_rows(|set| !set).collect::<Vec<_>>(), [1]);
+    assert_eq!(field.empty_cols(|set| !set).collect::<Vec<_>>(), [1, 3]);
+    assert_eq!(
+        field.positions_where(|set| *set).collect::<Vec<_>>(),
+        [(0, 0), (2, 2)]
+    );
+}
+
+#[test]
+fn overlay_replaces_set_tiles() {
+    let mut field = Field::new_filled(2, 3, '.');
+    let mut layer = Field::new_filled(2, 3, None);
+    *layer.get_mut((1, 0)) = Some('#');
+    *layer.get_mut((2, 1)) = Some('@');
+
+    field.overlay(&layer);
+
+    assert_eq!(field.row(0).collect::<String>(), ".#.");
+    assert_eq!(field.row(1).collect::<String>(), "..@");
+}
@@ -0,0 +1,244 @@
+use common::field::Field;
+use proptest::prelude::*;
+
+/// A field where every tile holds its own index, so any tile can be checked against its position.
+fn indexed(width: usize, height: usize) -> Field<usize> {
+    Field::new(height, width, (0..width * height).collect())
+}
+
+/// Rectangular fields along with single rows, single columns and fields with no tiles at all.
+fn dimensions() -> impl Strategy<Value = (usize, usize)> {
+    prop_oneof![
+        (1..10usize, 1..10usize),
+        (Just(1usize), 1..20usize),
+        (1..20usize, Just(1usize)),
+        (0..5usize, Just(0usize)),
+        (Just(0usize), 0..5usize),
+    ]
+}
+
+/// Takes items from alternating ends of `iter` according to `from_back`, then drains the rest from
+/// the front, returning everything in the order it would appear going forwards.
+fn drain_both_ends<I: DoubleEndedIterator + ExactSizeIterator>(
+    mut iter: I,
+    from_back: &[bool],
+) -> Vec<I::Item> {
+    let mut front = Vec::new();
+    let mut back = Vec::new();
+
+    for from_back in from_back {
+        let remaining = iter.len();
+        let item = if *from_back {
+            iter.next_back().map(|item| back.push(item))
+        } else {
+            iter.next().map(|item| front.push(item))
+        };
+
+        if item.is_some() {
+            assert_eq!(iter.len(), remaining - 1);
+        } else {
+            assert_eq!(remaining, 0);
+        }
+    }
+
+    front.extend(iter.by_ref());
+    assert!(iter.next().is_none());
+    assert!(iter.next_back().is_none());
+
+    front.extend(back.into_iter().rev());
+    front
+}
+
+proptest! {
+    #[test]
+    fn iter_yields_row_major_coordinates((width, height) in dimensions()) {
+        let field = indexed(width, height);
+
+        prop_assert_eq!(field.iter().len(), width * height);
+
+        for (idx, ((x, y), tile)) in field.iter().enumerate() {
+            prop_assert_eq!((x, y), (idx % width, idx / width));
+            prop_assert_eq!(*tile, idx);
+            prop_assert_eq!(field.get((x, y)), tile);
+        }
+    }
+
+    #[test]
+    fn iter_is_double_ended(
+        (width, height) in dimensions(),
+        from_back in prop::collection::vec(any::<bool>(), 0..40),
+    ) {
+        let field = indexed(width, height);
+        let forwards = field.iter().collect::<Vec<_>>();
+
+        prop_assert_eq!(drain_both_ends(field.iter(), &from_back), forwards.clone());
+        prop_assert_eq!(
+            field.iter().rev().collect::<Vec<_>>(),
+            forwards.into_iter().rev().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn rows_match_row((width, height) in dimensions()) {
+        let field = indexed(width, height);
+
+        prop_assert_eq!(field.rows().len(), height);
+        prop_assert_eq!(field.num_rows(), height);
+
+        for (y, row) in field.rows().enumerate() {
+            let expected = (0..width).map(|x| y * width + x).collect::<Vec<_>>();
+
+            prop_assert_eq!(row.len(), width);
+            prop_assert_eq!(row.clone().copied().collect::<Vec<_>>(), expected.clone());
+            prop_assert_eq!(field.row(y).copied().collect::<Vec<_>>(), expected);
+
+            for x in 0..width {
+                prop_assert_eq!(*row.get(x), y * width + x);
+            }
+        }
+    }
+
+    #[test]
+    fn cols_match_col((width, height) in dimensions()) {
+        let field = indexed(width, height);
+
+        prop_assert_eq!(field.cols().len(), width);
+        prop_assert_eq!(field.num_cols(), width);
+
+        for (x, col) in field.cols().enumerate() {
+            let expected = (0..height).map(|y| y * width + x).collect::<Vec<_>>();
+
+            prop_assert_eq!(col.len(), height);
+            prop_assert_eq!(col.clone().copied().collect::<Vec<_>>(), expected.clone());
+            prop_assert_eq!(field.col(x).copied().collect::<Vec<_>>(), expected);
+
+            for y in 0..height {
+                prop_assert_eq!(*col.get(y), y * width + x);
+            }
+        }
+    }
+
+    #[test]
+    fn rows_and_cols_are_double_ended(
+        (width, height) in dimensions(),
+        from_back in prop::collection::vec(any::<bool>(), 0..40),
+    ) {
+        let field = indexed(width, height);
+
+        let rows = drain_both_ends(field.rows(), &from_back)
+            .into_iter()
+            .map(|row| row.copied().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let expected_rows = (0..height)
+            .map(|y| field.row(y).copied().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        prop_assert_eq!(rows, expected_rows);
+
+        let cols = drain_both_ends(field.cols(), &from_back)
+            .into_iter()
+            .map(|col| col.copied().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let expected_cols = (0..width)
+            .map(|x| field.col(x).copied().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        prop_assert_eq!(cols, expected_cols);
+    }
+
+    #[test]
+    fn row_and_col_are_double_ended(
+        (width, height) in dimensions(),
+        from_back in prop::collection::vec(any::<bool>(), 0..40),
+    ) {
+        let field = indexed(width, height);
+
+        for y in 0..height {
+            let row = drain_both_ends(field.row(y), &from_back);
+            prop_assert_eq!(row, field.row(y).collect::<Vec<_>>());
+            prop_assert_eq!(
+                field.row(y).rev().copied().collect::<Vec<_>>(),
+                (0..width).rev().map(|x| y * width + x).collect::<Vec<_>>()
+            );
+        }
+
+        for x in 0..width {
+            let col = drain_both_ends(field.col(x), &from_back);
+            prop_assert_eq!(col, field.col(x).collect::<Vec<_>>());
+            prop_assert_eq!(
+                field.col(x).rev().copied().collect::<Vec<_>>(),
+                (0..height).rev().map(|y| y * width + x).collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[test]
+    fn transforms_move_tiles((width, height) in dimensions()) {
+        let field = indexed(width, height);
+
+        let mut transposed = field.clone();
+        transposed.transpose();
+        prop_assert_eq!((transposed.num_cols(), transposed.num_rows()), (height, width));
+
+        let mut cw = field.clone();
+        cw.rotate_cw();
+        let mut ccw = field.clone();
+        ccw.rotate_ccw();
+        let mut flipped_h = field.clone();
+        flipped_h.flip_h();
+        let mut flipped_v = field.clone();
+        flipped_v.flip_v();
+
+        for ((x, y), tile) in field.iter() {
+            prop_assert_eq!(transposed.get((y, x)), tile);
+            prop_assert_eq!(cw.get((height - 1 - y, x)), tile);
+            prop_assert_eq!(ccw.get((y, width - 1 - x)), tile);
+            prop_assert_eq!(flipped_h.get((width - 1 - x, y)), tile);
+            prop_assert_eq!(flipped_v.get((x, height - 1 - y)), tile);
+        }
+    }
+
+    #[test]
+    fn four_rotations_are_identity((width, height) in dimensions()) {
+        let field = indexed(width, height);
+        let mut rotated = field.clone();
+
+        for _ in 0..4 {
+            rotated.rotate_cw();
+        }
+        prop_assert!(rotated == field);
+
+        rotated.rotate_cw();
+        rotated.rotate_ccw();
+        prop_assert!(rotated == field);
+    }
+
+    #[test]
+    fn mutable_iterators_visit_every_tile((width, height) in dimensions()) {
+        let mut field = indexed(width, height);
+
+        for ((x, y), tile) in field.iter_mut() {
+            prop_assert_eq!(*tile, y * width + x);
+            *tile *= 2;
+        }
+
+        for (y, row) in field.rows_mut().enumerate() {
+            prop_assert_eq!(row.len(), width);
+            for (x, tile) in row.iter_mut().enumerate() {
+                prop_assert_eq!(*tile, 2 * (y * width + x));
+                *tile += 1;
+            }
+        }
+
+        prop_assert_eq!(field.cols_mut().len(), width);
+        for (x, col) in field.cols_mut().enumerate() {
+            prop_assert_eq!(col.len(), height);
+            for (y, tile) in col.into_iter().enumerate() {
+                prop_assert_eq!(*tile, 2 * (y * width + x) + 1);
+            }
+        }
+
+        prop_assert_eq!(
+            field.rows_mut().rev().map(|row| row.to_vec()).collect::<Vec<_>>(),
+            field.rows().rev().map(|row| row.copied().collect::<Vec<_>>()).collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,188 @@
+use std::{
+    fs,
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpListener,
+    path::PathBuf,
+    thread::{self, JoinHandle},
+};
+
+use common::input::{
+    default_path, workspace_root, Cache, Cached, Http, InputError, InputProvider, Session, Source,
+};
+use tempfile::TempDir;
+
+#[test]
+fn arguments_pick_the_source() {
+    assert_eq!(Source::from_arg(3, Some("-")), Source::Stdin);
+    assert_eq!(
+        Source::from_arg(3, Some("example.txt")),
+        Source::Path(PathBuf::from("example.txt"))
+    );
+    assert_eq!(Source::from_arg(3, None), Source::Default { day: 3 });
+    assert_eq!(Source::Stdin.path(), None);
+}
+
+#[test]
+fn default_inputs_live_at_the_workspace_root() {
+    assert!(workspace_root().join("Cargo.toml").is_file());
+    assert!(workspace_root().join("common").is_dir());
+
+    assert_eq!(
+        default_path(7),
+        workspace_root().join("inputs").join("day-07.txt")
+    );
+    assert_eq!(Source::Default { day: 12 }.path(), Some(default_path(12)));
+}
+
+#[test]
+fn reads_files() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("input.txt");
+    fs::write(&path, "1abc2\npqr3stu8vwx\n").unwrap();
+
+    let source = Source::Path(path);
+    let mut streamed = String::new();
+    source
+        .open()
+        .unwrap()
+        .read_to_string(&mut streamed)
+        .unwrap();
+
+    assert_eq!(source.read().unwrap(), "1abc2\npqr3stu8vwx\n");
+    assert_eq!(streamed, "1abc2\npqr3stu8vwx\n");
+}
+
+#[test]
+fn missing_files_are_reported() {
+    let path = workspace_root().join("inputs").join("no-such-day.txt");
+    let err = Source::Path(path.clone()).read().unwrap_err();
+
+    assert!(matches!(&err, InputError::Missing(missing) if *missing == path));
+    assert_eq!(
+        err.to_string(),
+        format!("No input found at {}", path.display())
+    );
+}
+
+/// Serves a single request on a local port with `status` and `body`, returning the url to reach it
+/// and a handle giving back the request it received.
+fn stub_server(status: &'static str, body: &'static str) -> (String, JoinHandle<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+
+    let handle = thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(stream);
+
+        let mut request = String::new();
+        // Headers end with an empty line
+        while reader.read_line(&mut request).unwrap() > 2 {}
+
+        write!(
+            reader.get_mut(),
+            "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        )
+        .unwrap();
+
+        request
+    });
+
+    (url, handle)
+}
+
+/// A cache in a new temporary directory, which is removed when the returned handle is dropped.
+fn temp_cache() -> (TempDir, Cache) {
+    let dir = TempDir::new().unwrap();
+    let cache = Cache::new(dir.path());
+    (dir, cache)
+}
+
+#[test]
+fn cache_is_keyed_by_year_and_day() {
+    let (_dir, cache) = temp_cache();
+
+    assert!(cache.path(2023, 5).ends_with("2023/day-05.txt"));
+    assert!(matches!(cache.input(2023, 5), Err(InputError::Missing(_))));
+
+    cache.store(2023, 5, "seeds: 79 14 55 13\n").unwrap();
+
+    assert_eq!(cache.input(2023, 5).unwrap(), "seeds: 79 14 55 13\n");
+    assert!(matches!(cache.input(2022, 5), Err(InputError::Missing(_))));
+}
+
+#[test]
+fn failed_stores_are_reported_as_writes() {
+    let dir = TempDir::new().unwrap();
+    let file = dir.path().join("not-a-dir");
+    fs::write(&file, "").unwrap();
+
+    // The year's directory can't be made inside a file
+    let err = Cache::new(&file).store(2023, 5, "input").unwrap_err();
+
+    assert!(matches!(err, InputError::Unwritable(ref path, _) if path.starts_with(&file)));
+    assert!(err.to_string().starts_with("Could not write "));
+}
+
+#[test]
+fn http_sends_the_session_cookie() {
+    let (url, server) = stub_server("200 OK", "0 3 6 9 12 15\n");
+    let http = Http::new(Session::new("secret-token")).with_base_url(url);
+
+    assert_eq!(http.input(2023, 9).unwrap(), "0 3 6 9 12 15\n");
+
+    let request = server.join().unwrap();
+    assert!(request.starts_with("GET /2023/day/9/input HTTP/1.1\r\n"));
+    assert!(request.contains("session=secret-token"));
+}
+
+#[test]
+fn http_errors_never_mention_the_session() {
+    let (url, server) = stub_server("400 Bad Request", "Please log in");
+    let session = Session::new("secret-token");
+    let err = Http::new(session.clone())
+        .with_base_url(url.clone())
+        .input(2023, 1)
+        .unwrap_err();
+    server.join().unwrap();
+
+    let message = err.to_string();
+    assert_eq!(
+        message,
+        format!("Could not download {url}/2023/day/1/input: status 400")
+    );
+    assert!(!format!("{err:?}").contains("secret-token"));
+    assert!(!format!("{session:?}").contains("secret-token"));
+}
+
+#[test]
+fn cached_downloads_each_input_once() {
+    let (_dir, cache) = temp_cache();
+    let (url, server) = stub_server("200 OK", "rn=1,cm-\n");
+    let provider = Cached::new(
+        cache.clone(),
+        Http::new(Session::new("secret-token")).with_base_url(url),
+    );
+
+    assert_eq!(provider.input(2023, 15).unwrap(), "rn=1,cm-\n");
+    server.join().unwrap();
+
+    // The server has shut down, so this can only come from the cache
+    assert_eq!(provider.input(2023, 15).unwrap(), "rn=1,cm-\n");
+    assert_eq!(cache.input(2023, 15).unwrap(), "rn=1,cm-\n");
+}
+
+#[test]
+fn session_comes_from_the_variable() {
+    let session = Session::from_var(Some("  from-env\n")).unwrap();
+
+    assert_eq!(format!("{session:?}"), "Session(<redacted>)");
+    assert!(matches!(
+        Session::from_var(None),
+        Err(InputError::NoSession)
+    ));
+    assert!(matches!(
+        Session::from_var(Some(" \n")),
+        Err(InputError::NoSession)
+    ));
+}
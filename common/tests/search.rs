@@ -0,0 +1,118 @@
+use common::{
+    field::Field,
+    search::{astar, bfs, dijkstra, reachable, FieldGraph, Neighbors},
+};
+
+const MAZE: &str = "\
+S.#.....
+.##.###.
+....#...
+.####.#.
+......#E
+";
+
+fn find(field: &Field<char>, target: char) -> (usize, usize) {
+    field
+        .iter()
+        .find(|(_, tile)| **tile == target)
+        .map(|(pos, _)| pos)
+        .unwrap()
+}
+
+fn walls(tile: &char) -> Option<u64> {
+    (*tile != '#').then_some(1)
+}
+
+#[test]
+fn bfs_finds_shortest_route_through_field() {
+    let field: Field<char> = MAZE.parse().unwrap();
+    let (start, end) = (find(&field, 'S'), find(&field, 'E'));
+    let graph = FieldGraph::new(&field, walls);
+
+    let path = bfs(&graph, start, |pos| *pos == end).unwrap();
+
+    assert_eq!(path.cost, 15);
+    assert_eq!(path.states.len(), 16);
+    assert_eq!(path.states.first(), Some(&start));
+    assert_eq!(path.states.last(), Some(&end));
+    assert!(path
+        .states
+        .windows(2)
+        .all(|step| graph.neighbors(&step[0]).any(|(pos, _)| pos == step[1])));
+}
+
+#[test]
+fn walled_off_goal_is_not_found() {
+    let field: Field<char> = "S#.\n##E\n".parse().unwrap();
+    let graph = FieldGraph::new(&field, walls);
+    let end = find(&field, 'E');
+
+    assert_eq!(bfs(&graph, (0, 0), |pos| *pos == end), None);
+    assert_eq!(dijkstra(&graph, (0, 0), |pos| *pos == end), None);
+    assert_eq!(reachable(&graph, (0, 0)).len(), 1);
+}
+
+#[test]
+fn weighted_searches_agree() {
+    let field: Field<char> = "\
+19111
+11191
+99991
+11111
+19999
+"
+    .parse()
+    .unwrap();
+    let graph = FieldGraph::new(&field, |tile: &char| tile.to_digit(10).map(u64::from));
+    let end = (4, 4);
+
+    let cheapest = dijkstra(&graph, (0, 0), |pos| *pos == end).unwrap();
+    let guided = astar(
+        &graph,
+        (0, 0),
+        |pos| *pos == end,
+        |&(x, y)| (end.0 - x + end.1 - y) as u64,
+    )
+    .unwrap();
+
+    assert_eq!(cheapest.cost, 18);
+    assert_eq!(guided.cost, cheapest.cost);
+    // Counting steps ignores the weights, so the cheapest route isn't the shortest
+    assert_eq!(bfs(&graph, (0, 0), |pos| *pos == end).unwrap().cost, 8);
+    assert_eq!(cheapest.states.len(), 11);
+}
+
+/// Reaches numbers by either adding one or doubling, where doubling is more expensive.
+struct Numbers;
+
+impl Neighbors for Numbers {
+    type State = u32;
+
+    fn neighbors(&self, state: &u32) -> impl Iterator<Item = (u32, u64)> {
+        [(state + 1, 1), (state * 2, 3)].into_iter()
+    }
+}
+
+#[test]
+fn searches_any_state_type() {
+    // 1 -> 2 -> 4 -> 8 -> 16 -> 32 by doubling is 5 steps but costs 15
+    assert_eq!(bfs(&Numbers, 1, |n| *n == 32).unwrap().cost, 5);
+
+    let path = dijkstra(&Numbers, 1, |n| *n == 10).unwrap();
+    assert_eq!(path.states, [1, 2, 3, 4, 5, 10]);
+    assert_eq!(path.cost, 7);
+}
+
+#[test]
+fn reachable_counts_steps() {
+    let field: Field<char> = MAZE.parse().unwrap();
+    let distances = reachable(&FieldGraph::new(&field, walls), (0, 0));
+
+    assert_eq!(distances[&(0, 0)], 0);
+    assert_eq!(distances[&(3, 0)], 7);
+    assert_eq!(distances[&find(&field, 'E')], 15);
+    assert_eq!(
+        distances.len(),
+        field.iter().filter(|(_, t)| **t != '#').count()
+    );
+}
@@ -0,0 +1,139 @@
+use std::{collections::HashSet, ops::RangeInclusive};
+
+use common::{field::Field, span::SurroundingIter};
+
+fn field(input: &str) -> Field<char> {
+    input.parse().unwrap()
+}
+
+fn surrounding(
+    cols: RangeInclusive<usize>,
+    row: usize,
+    width: usize,
+    height: usize,
+) -> Vec<(usize, usize)> {
+    SurroundingIter::new(cols, row, width, height).collect()
+}
+
+#[test]
+fn surrounding_skips_the_run() {
+    assert_eq!(
+        surrounding(1..=2, 1, 5, 3),
+        [
+            (0, 0),
+            (1, 0),
+            (2, 0),
+            (3, 0),
+            (0, 1),
+            (3, 1),
+            (0, 2),
+            (1, 2),
+            (2, 2),
+            (3, 2)
+        ]
+    );
+}
+
+#[test]
+fn surrounding_stays_inside_corners() {
+    assert_eq!(
+        surrounding(0..=1, 0, 4, 3),
+        [(2, 0), (0, 1), (1, 1), (2, 1)]
+    );
+    assert_eq!(
+        surrounding(2..=3, 0, 4, 3),
+        [(1, 0), (1, 1), (2, 1), (3, 1)]
+    );
+    assert_eq!(surrounding(0..=0, 2, 4, 3), [(0, 1), (1, 1), (1, 2)]);
+    assert_eq!(
+        surrounding(2..=3, 2, 4, 3),
+        [(1, 1), (2, 1), (3, 1), (1, 2)]
+    );
+}
+
+#[test]
+fn surrounding_stays_inside_edges() {
+    assert_eq!(
+        surrounding(0..=3, 1, 4, 3),
+        [
+            (0, 0),
+            (1, 0),
+            (2, 0),
+            (3, 0),
+            (0, 2),
+            (1, 2),
+            (2, 2),
+            (3, 2)
+        ]
+    );
+    assert_eq!(surrounding(1..=1, 0, 3, 1), [(0, 0), (2, 0)]);
+    assert_eq!(surrounding(0..=0, 1, 1, 3), [(0, 0), (0, 2)]);
+    assert_eq!(surrounding(0..=0, 0, 1, 1), []);
+    assert_eq!(surrounding(0..=0, 0, 0, 0), []);
+}
+
+#[test]
+fn surrounding_a_single_cell_matches_neighbors8() {
+    let field = field("....\n....\n....\n");
+
+    for ((x, y), _) in field.iter() {
+        let expected = field.neighbors8((x, y)).collect::<HashSet<_>>();
+        let surrounding = field.surrounding(x..=x, y).collect::<Vec<_>>();
+
+        assert_eq!(surrounding.len(), expected.len(), "around ({x}, {y})");
+        assert_eq!(surrounding.into_iter().collect::<HashSet<_>>(), expected);
+    }
+}
+
+#[test]
+fn numbers_touching_every_edge_and_corner() {
+    let field = field(
+        "\
+12..34
+5....6
+..78..
+9....0
+",
+    );
+
+    assert_eq!(
+        field.numbers(|char| char.to_digit(10)).collect::<Vec<_>>(),
+        [
+            ((0..=1, 0), 12),
+            ((4..=5, 0), 34),
+            ((0..=0, 1), 5),
+            ((5..=5, 1), 6),
+            ((2..=3, 2), 78),
+            ((0..=0, 3), 9),
+            ((5..=5, 3), 0)
+        ]
+    );
+}
+
+#[test]
+fn numbers_do_not_wrap_onto_the_next_row() {
+    let field = field("..12\n34..\n5678\n9...\n");
+
+    assert_eq!(
+        field.numbers(|char| char.to_digit(10)).collect::<Vec<_>>(),
+        [
+            ((2..=3, 0), 12),
+            ((0..=1, 1), 34),
+            ((0..=3, 2), 5678),
+            ((0..=0, 3), 9)
+        ]
+    );
+}
+
+#[test]
+fn surrounding_runs_off_the_field() {
+    assert_eq!(surrounding(5..=5, 0, 3, 3), []);
+    assert_eq!(surrounding(3..=4, 1, 3, 3), [(2, 0), (2, 1), (2, 2)]);
+    assert_eq!(
+        surrounding(2..=6, 1, 3, 3),
+        [(1, 0), (2, 0), (1, 1), (1, 2), (2, 2)]
+    );
+    assert_eq!(surrounding(0..=0, 3, 3, 3), [(0, 2), (1, 2)]);
+    assert_eq!(surrounding(0..=0, 5, 3, 3), []);
+    assert_eq!(surrounding(4..=4, 4, 3, 3), []);
+}
@@ -0,0 +1,142 @@
+use common::{
+    field::Field,
+    sparse_field::{Bounds, SparseField},
+};
+
+#[test]
+fn unset_tiles_read_as_default() {
+    let mut field = SparseField::new('.');
+
+    assert_eq!(field.get((-100, 5)), &'.');
+    assert!(field.is_empty());
+    assert_eq!(field.bounds(), None);
+    assert_eq!(field.rows().count(), 0);
+
+    field.set((-100, 5), '#');
+    *field.get_mut((3, -2)) = '@';
+
+    assert_eq!(field.get((-100, 5)), &'#');
+    assert_eq!(field.get((3, -2)), &'@');
+    assert_eq!(field.len(), 2);
+
+    assert_eq!(field.remove((3, -2)), Some('@'));
+    assert_eq!(field.get((3, -2)), &'.');
+}
+
+#[test]
+fn bounds_cover_negative_coordinates() {
+    let mut field = SparseField::new(0);
+    field.set((-2, 1), 1);
+    field.set((3, -4), 2);
+    field.set((0, 0), 3);
+
+    let bounds = field.bounds().unwrap();
+
+    assert_eq!(
+        bounds,
+        Bounds {
+            x: -2..=3,
+            y: -4..=1
+        }
+    );
+    assert_eq!((bounds.width(), bounds.height()), (6, 6));
+    assert!(bounds.contains((-2, -4)));
+    assert!(!bounds.contains((4, 0)));
+    assert_eq!((field.num_cols(), field.num_rows()), (6, 6));
+}
+
+#[test]
+fn rows_and_cols_span_bounds() {
+    let mut field = SparseField::new('.');
+    field.set((-1, -1), 'a');
+    field.set((1, 0), 'b');
+
+    let rows = field
+        .rows()
+        .map(|row| row.collect::<String>())
+        .collect::<Vec<_>>();
+    assert_eq!(rows, ["a..", "..b"]);
+
+    let cols = field
+        .cols()
+        .map(|col| col.collect::<String>())
+        .collect::<Vec<_>>();
+    assert_eq!(cols, ["a.", "..", ".b"]);
+
+    assert_eq!(field.row(0).rev().collect::<String>(), "b..");
+    assert_eq!(field.col(-1).collect::<String>(), "a.");
+}
+
+#[test]
+fn converts_to_and_from_field() {
+    let dense: Field<char> = "#..\n..#\n".parse().unwrap();
+    let mut sparse = SparseField::from_field(&dense, '.');
+
+    assert_eq!(sparse.len(), 2);
+    assert_eq!(sparse.get((2, 1)), &'#');
+
+    sparse.set((-1, 2), '@');
+    let dense = sparse.to_field();
+
+    assert_eq!((dense.num_cols(), dense.num_rows()), (4, 3));
+    assert_eq!(dense.row(0).collect::<String>(), ".#..");
+    assert_eq!(dense.row(1).collect::<String>(), "...#");
+    assert_eq!(dense.row(2).collect::<String>(), "@...");
+    assert_eq!(Field::from(&SparseField::new('.')).num_rows(), 0);
+}
+
+#[test]
+fn round_trip_keeps_default_edges() {
+    let dense: Field<char> = "....\n.#..\n....\n".parse().unwrap();
+    let sparse = SparseField::from_field(&dense, '.');
+
+    assert_eq!(sparse.len(), 1);
+    assert_eq!(sparse.bounds(), Some(Bounds { x: 0..=3, y: 0..=2 }));
+
+    let round_trip = sparse.to_field();
+    assert_eq!((round_trip.num_cols(), round_trip.num_rows()), (4, 3));
+    assert_eq!(
+        round_trip.iter().collect::<Vec<_>>(),
+        dense.iter().collect::<Vec<_>>()
+    );
+
+    let empty: Field<char> = "...\n...\n".parse().unwrap();
+    let round_trip = SparseField::from_field(&empty, '.').to_field();
+    assert_eq!((round_trip.num_cols(), round_trip.num_rows()), (3, 2));
+}
+
+#[test]
+fn bounds_never_shrink_below_the_source_field() {
+    let dense: Field<char> = "#..\n...\n".parse().unwrap();
+    let mut sparse = SparseField::from_field(&dense, '.');
+
+    assert_eq!(sparse.remove((0, 0)), Some('#'));
+    assert!(sparse.is_empty());
+    assert_eq!(sparse.bounds(), Some(Bounds { x: 0..=2, y: 0..=1 }));
+
+    // Tiles outside of the source field still grow the bounds, and shrink them again when removed
+    sparse.set((-1, 4), '#');
+    assert_eq!(
+        sparse.bounds(),
+        Some(Bounds {
+            x: -1..=2,
+            y: 0..=4
+        })
+    );
+    sparse.remove((-1, 4));
+    assert_eq!(sparse.bounds(), Some(Bounds { x: 0..=2, y: 0..=1 }));
+}
+
+#[test]
+fn get_mut_sets_the_tile() {
+    let mut field = SparseField::new('.');
+    field.set((0, 0), '#');
+
+    // Looking at a tile doesn't set it, but borrowing it mutably does
+    assert_eq!(field.get((5, 5)), &'.');
+    assert_eq!(field.num_cols(), 1);
+
+    field.get_mut((5, 5));
+    assert!(field.is_set((5, 5)));
+    assert_eq!((field.num_cols(), field.num_rows()), (6, 6));
+}
@@ -0,0 +1,103 @@
+use common::solution::Solution;
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use day_1::Day1;
+
+const PATTERNS: [(&str, u32); 18] = [
+    ("1", 1),
+    ("2", 2),
+    ("3", 3),
+    ("4", 4),
+    ("5", 5),
+    ("6", 6),
+    ("7", 7),
+    ("8", 8),
+    ("9", 9),
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+/// About 1 MB of calibration lines made of random letters with digits and spelled out digits
+/// mixed in.
+fn document() -> Vec<String> {
+    // xorshift, so the document is the same on every run
+    let mut state = 0x9e37_79b9_7f4a_7c15_u64;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+
+    let mut lines = Vec::new();
+    let mut size = 0;
+
+    while size < 1 << 20 {
+        let mut line = String::new();
+
+        for _ in 0..next() % 12 + 4 {
+            match next() % 10 {
+                0 => line.push_str(PATTERNS[(next() % 9) as usize].0),
+                1 => line.push_str(PATTERNS[(next() % 9 + 9) as usize].0),
+                _ => line.push((b'a' + (next() % 26) as u8) as char),
+            }
+        }
+
+        size += line.len() + 1;
+        lines.push(line);
+    }
+
+    lines
+}
+
+/// Part 2 the way it used to be done, searching each line once for every pattern.
+fn match_indices(lines: &[String]) -> u64 {
+    lines
+        .iter()
+        .map(|line| {
+            let (mut first_index, mut first) = (usize::MAX, 0);
+            let (mut last_index, mut last) = (0, 0);
+
+            for (key, value) in PATTERNS {
+                for (index, _) in line.match_indices(key) {
+                    if index < first_index {
+                        first_index = index;
+                        first = value;
+                    }
+                    if index >= last_index {
+                        last_index = index;
+                        last = value;
+                    }
+                }
+            }
+
+            u64::from(first * 10 + last)
+        })
+        .sum()
+}
+
+fn bench_calibration(c: &mut Criterion) {
+    let lines = document();
+    assert_eq!(match_indices(&lines), Day1::part2(&lines));
+
+    let mut group = c.benchmark_group("day 1 part 2");
+    group.throughput(Throughput::Bytes(
+        lines.iter().map(|line| line.len() as u64 + 1).sum(),
+    ));
+    group.bench_function("match_indices", |b| {
+        b.iter(|| match_indices(black_box(&lines)))
+    });
+    group.bench_function("aho-corasick", |b| {
+        b.iter(|| Day1::part2(black_box(&lines)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_calibration);
+criterion_main!(benches);
@@ -0,0 +1,338 @@
+use std::str::FromStr;
+
+use aho_corasick::AhoCorasick;
+use common::{
+    parse::{self, ParseError},
+    solution::Solution,
+};
+use phf::phf_map;
+
+static PATTERNS: phf::Map<&str, u32> = phf_map! {
+    "1" => 1,
+    "2" => 2,
+    "3" => 3,
+    "4" => 4,
+    "5" => 5,
+    "6" => 6,
+    "7" => 7,
+    "8" => 8,
+    "9" => 9,
+    "one" => 1,
+    "two" => 2,
+    "three" => 3,
+    "four" => 4,
+    "five" => 5,
+    "six" => 6,
+    "seven" => 7,
+    "eight" => 8,
+    "nine" => 9,
+};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<String>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        Ok(input.lines().map(str::to_string).collect())
+    }
+
+    fn part1(input: &Vec<String>) -> u64 {
+        calibration_sum(input, false)
+    }
+
+    fn part2(input: &Vec<String>) -> u64 {
+        calibration_sum(input, true)
+    }
+}
+
+/// Sums the first and last digit of every line, also matching spelled out digits if `spelled` is
+/// set.
+fn calibration_sum(lines: &[String], spelled: bool) -> u64 {
+    DigitMatcher::new(spelled)
+        .calibration_sum(lines)
+        .expect("single digits can't add up to more than a u64")
+}
+
+/// The words to look for in a document along with the numbers they stand for.
+///
+/// Vocabularies can be read from a small subset of TOML, where `[words]` lists each word along
+/// with its number and the settings before it control how they are matched:
+///
+/// ```toml
+/// # Match "Eins" as well as "eins"
+/// case_insensitive = true
+/// # Also match "snie" and the other words spelled backwards
+/// reversed = true
+/// # Match the digits 0 to 9
+/// digits = true
+///
+/// [words]
+/// null = 0
+/// eins = 1
+/// zehn = 10
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Vocabulary {
+    words: Vec<(String, u32)>,
+    case_insensitive: bool,
+}
+
+impl Vocabulary {
+    /// A vocabulary with no words in it.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The digits 1 to 9.
+    pub fn digits() -> Self {
+        Self::from_patterns(|key| key.len() == 1)
+    }
+
+    /// The digits 1 to 9 along with their names in English.
+    pub fn english() -> Self {
+        Self::from_patterns(|_| true)
+    }
+
+    fn from_patterns(mut include: impl FnMut(&str) -> bool) -> Self {
+        let words = PATTERNS
+            .entries()
+            .filter(|(key, _)| include(key))
+            .map(|(key, value)| (key.to_string(), *value))
+            .collect();
+
+        Self {
+            words,
+            case_insensitive: false,
+        }
+    }
+
+    /// Adds `word` as meaning `value`, returning what it already means instead if that is
+    /// something else.
+    pub fn add(&mut self, word: impl Into<String>, value: u32) -> Result<(), u32> {
+        let word = word.into();
+
+        match self.get(&word) {
+            Some(existing) if existing != value => Err(existing),
+            Some(_) => Ok(()),
+            None => {
+                self.words.push((word, value));
+                Ok(())
+            }
+        }
+    }
+
+    /// What `word` means, if it is in the vocabulary.
+    pub fn get(&self, word: &str) -> Option<u32> {
+        self.words
+            .iter()
+            .find(|(existing, _)| existing == word)
+            .map(|(_, value)| *value)
+    }
+
+    pub fn words(&self) -> impl Iterator<Item = (&str, u32)> + '_ {
+        self.words
+            .iter()
+            .map(|(word, value)| (word.as_str(), *value))
+    }
+
+    pub fn is_case_insensitive(&self) -> bool {
+        self.case_insensitive
+    }
+
+    pub fn case_insensitive(mut self, case_insensitive: bool) -> Self {
+        self.case_insensitive = case_insensitive;
+        self
+    }
+
+    /// Adds every word spelled backwards. Reversed words that would clash with another word are
+    /// left out.
+    pub fn with_reversed(mut self) -> Self {
+        let reversed = self
+            .words
+            .iter()
+            .map(|(word, value)| (word.chars().rev().collect::<String>(), *value))
+            .collect::<Vec<_>>();
+
+        for (word, value) in reversed {
+            let _ = self.add(word, value);
+        }
+
+        self
+    }
+}
+
+impl FromStr for Vocabulary {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut vocabulary = Vocabulary::new();
+        let mut reversed = false;
+        let mut in_words = false;
+
+        for line in parse::lines(s) {
+            let text = line.text().trim();
+
+            if text.is_empty() || text.starts_with('#') {
+                continue;
+            }
+
+            if text == "[words]" {
+                in_words = true;
+                continue;
+            }
+
+            if text.starts_with('[') {
+                return Err(line.error(text, "unknown section"));
+            }
+
+            let (key, value) = line.expect(text.split_once('='), "\"=\"")?;
+            let key = key.trim();
+            let key = key
+                .strip_prefix('"')
+                .and_then(|key| key.strip_suffix('"'))
+                .unwrap_or(key);
+            let value = value.split('#').next().unwrap_or_default().trim();
+
+            if in_words {
+                if key.is_empty() {
+                    return Err(line.error(key, "empty word"));
+                }
+
+                let number = line.parse(value, "number")?;
+
+                if let Err(existing) = vocabulary.add(key, number) {
+                    return Err(line.error(key, format!("word already means {existing}")));
+                }
+
+                continue;
+            }
+
+            match key {
+                "case_insensitive" => vocabulary.case_insensitive = line.parse(value, "boolean")?,
+                "reversed" => reversed = line.parse(value, "boolean")?,
+                "digits" => {
+                    if line.parse(value, "boolean")? {
+                        for digit in 0..10 {
+                            if let Err(existing) = vocabulary.add(digit.to_string(), digit) {
+                                return Err(
+                                    line.error(key, format!("{digit} already means {existing}"))
+                                );
+                            }
+                        }
+                    }
+                }
+                _ => return Err(line.error(key, "unknown setting")),
+            }
+        }
+
+        Ok(if reversed {
+            vocabulary.with_reversed()
+        } else {
+            vocabulary
+        })
+    }
+}
+
+/// Finds numbers in a line with a single automaton built from a [`Vocabulary`], so each line is
+/// only scanned once however many words there are.
+pub struct DigitMatcher {
+    automaton: AhoCorasick,
+    values: Vec<u32>,
+    case_insensitive: bool,
+}
+
+impl DigitMatcher {
+    /// Matches the digits 1 to 9, along with their spelled out names if `spelled` is set.
+    pub fn new(spelled: bool) -> Self {
+        if spelled {
+            Self::from_vocabulary(&Vocabulary::english())
+        } else {
+            Self::from_vocabulary(&Vocabulary::digits())
+        }
+    }
+
+    pub fn from_vocabulary(vocabulary: &Vocabulary) -> Self {
+        let case_insensitive = vocabulary.is_case_insensitive();
+
+        let (patterns, values): (Vec<_>, Vec<_>) = vocabulary
+            .words()
+            .map(|(word, value)| {
+                if case_insensitive {
+                    (word.to_lowercase(), value)
+                } else {
+                    (word.to_string(), value)
+                }
+            })
+            .unzip();
+
+        Self {
+            automaton: AhoCorasick::new(patterns).unwrap(),
+            values,
+            case_insensitive,
+        }
+    }
+
+    /// Finds the first and last number in `line`, which may overlap (such as the "two" and "one"
+    /// in "twone") or be the same number.
+    pub fn first_and_last(&self, line: &str) -> Option<(u32, u32)> {
+        let lowercase;
+        let line = if self.case_insensitive {
+            lowercase = line.to_lowercase();
+            &lowercase
+        } else {
+            line
+        };
+
+        let mut matches = self.automaton.find_overlapping_iter(line);
+        let first = matches.next()?;
+
+        // Matches come out in the order they end, so one that ends later could still start earlier
+        // if a word contains another one
+        let (first, last) = matches.fold((first, first), |(first, last), found| {
+            (
+                if found.start() < first.start() {
+                    found
+                } else {
+                    first
+                },
+                if found.start() > last.start() {
+                    found
+                } else {
+                    last
+                },
+            )
+        });
+
+        Some((
+            self.values[first.pattern().as_usize()],
+            self.values[last.pattern().as_usize()],
+        ))
+    }
+
+    /// The first and last number in `line` written one after the other, or 0 if there aren't
+    /// any. For single digits this is the two digit number they make, and "ten" followed by
+    /// "three" gives 103.
+    ///
+    /// Returns `None` if the number is too big to fit in a `u64`, which large enough words in a
+    /// vocabulary can make.
+    pub fn calibration_value(&self, line: &str) -> Option<u64> {
+        let Some((first, last)) = self.first_and_last(line) else {
+            return Some(0);
+        };
+        let (first, last) = (u64::from(first), u64::from(last));
+
+        let shift = 10u64.checked_pow(last.checked_ilog10().unwrap_or(0) + 1)?;
+        first.checked_mul(shift)?.checked_add(last)
+    }
+
+    /// Sums the calibration value of every line, or returns `None` if the sum or any of the values
+    /// is too big to fit in a `u64`.
+    pub fn calibration_sum(&self, lines: &[impl AsRef<str>]) -> Option<u64> {
+        lines.iter().try_fold(0u64, |sum, line| {
+            sum.checked_add(self.calibration_value(line.as_ref())?)
+        })
+    }
+}
@@ -1,60 +1,80 @@
-use std::fs;
-
-use phf::phf_map;
-
-static PATTERNS: phf::Map<&str, u32> = phf_map! {
-    "1" => 1,
-    "2" => 2,
-    "3" => 3,
-    "4" => 4,
-    "5" => 5,
-    "6" => 6,
-    "7" => 7,
-    "8" => 8,
-    "9" => 9,
-    "one" => 1,
-    "two" => 2,
-    "three" => 3,
-    "four" => 4,
-    "five" => 5,
-    "six" => 6,
-    "seven" => 7,
-    "eight" => 8,
-    "nine" => 9,
-};
-
-fn main() {
-    let input = fs::read_to_string("input.txt").unwrap();
-    let lines = input.lines();
-
-    let sum: u32 = lines
-        .map(|val| {
-            let (mut first_index, mut first) = (i32::MAX, 0);
-
-            let (mut last_index, mut last) = (i32::MIN, 0);
-
-            for (index, number) in PATTERNS
-                .entries()
-                .map(|(key, value)| (*key, *value))
-                .map(|(key, value)| {
-                    val.match_indices(key)
-                        .map(move |(position, _)| (position, value))
-                })
-                .flatten()
-            {
-                if (index as i32) < first_index {
-                    first_index = index as i32;
-                    first = number;
-                }
-                if (index as i32) > last_index {
-                    last_index = index as i32;
-                    last = number;
-                }
+use std::{env, process::ExitCode};
+
+use common::{input::Source, solution::Solution};
+use day_1::{Day1, DigitMatcher, Vocabulary};
+
+const USAGE: &str = "Usage: day-1 [INPUT|-] [--vocabulary PATH]";
+
+fn main() -> ExitCode {
+    let mut input = None;
+    let mut vocabulary = None;
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if input.is_none() && (arg == "-" || !arg.starts_with("--")) {
+            input = Some(arg);
+            continue;
+        }
+
+        match (arg.as_str(), args.next()) {
+            ("--vocabulary", Some(path)) => vocabulary = Some(path),
+            _ => {
+                eprintln!("{USAGE}");
+                return ExitCode::FAILURE;
             }
-            first * 10 + last
-        })
-        .sum();
+        }
+    }
 
-    println!("{}", sum);
-}
+    let input = match Source::from_arg(1, input.as_deref()).read() {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    // A vocabulary replaces the puzzle's digits with its own words, so only its sum is printed
+    if let Some(path) = vocabulary {
+        let vocabulary = match Source::Path(path.into()).read() {
+            Ok(vocabulary) => vocabulary,
+            Err(err) => {
+                eprintln!("{err}");
+                return ExitCode::FAILURE;
+            }
+        };
 
+        let vocabulary = match vocabulary.parse::<Vocabulary>() {
+            Ok(vocabulary) => vocabulary,
+            Err(err) => {
+                eprintln!("Invalid vocabulary: {err}");
+                return ExitCode::FAILURE;
+            }
+        };
+
+        let lines = input.lines().collect::<Vec<_>>();
+
+        return match DigitMatcher::from_vocabulary(&vocabulary).calibration_sum(&lines) {
+            Some(sum) => {
+                println!("Calibration sum: {sum}");
+                ExitCode::SUCCESS
+            }
+            None => {
+                eprintln!("Calibration sum is too large to calculate");
+                ExitCode::FAILURE
+            }
+        };
+    }
+
+    let input = match Day1::parse(&input) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("Invalid input: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    println!("Part 1: {}", Day1::part1(&input));
+    println!("Part 2: {}", Day1::part2(&input));
+
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,140 @@
+use common::solution::Solution;
+use day_1::{Day1, DigitMatcher, Vocabulary};
+
+const EXAMPLE_1: &str = "\
+1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+";
+
+const EXAMPLE_2: &str = "\
+two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen
+";
+
+#[test]
+fn part1_example() {
+    assert_eq!(Day1::part1(&Day1::parse(EXAMPLE_1).unwrap()), 142);
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(Day1::part2(&Day1::parse(EXAMPLE_2).unwrap()), 281);
+}
+
+#[test]
+fn overlapping_words_are_both_found() {
+    let matcher = DigitMatcher::new(true);
+
+    assert_eq!(matcher.first_and_last("twone"), Some((2, 1)));
+    assert_eq!(matcher.first_and_last("oneight"), Some((1, 8)));
+    assert_eq!(matcher.first_and_last("xxsevenxx"), Some((7, 7)));
+    assert_eq!(matcher.first_and_last("abc"), None);
+    assert_eq!(matcher.calibration_value("abc"), Some(0));
+
+    // Only digits are matched when spelled out digits aren't
+    assert_eq!(
+        DigitMatcher::new(false).first_and_last("two3four5six"),
+        Some((3, 5))
+    );
+}
+
+#[test]
+fn vocabularies_are_read_from_toml() {
+    let vocabulary = include_str!("../vocabularies/german.toml")
+        .parse::<Vocabulary>()
+        .unwrap();
+    let matcher = DigitMatcher::from_vocabulary(&vocabulary);
+
+    assert_eq!(vocabulary.get("zwölf"), Some(12));
+    assert_eq!(vocabulary.get("0"), Some(0));
+    assert_eq!(matcher.first_and_last("EinsZweiDREI"), Some((1, 3)));
+    assert_eq!(matcher.first_and_last("xNullx"), Some((0, 0)));
+    assert_eq!(matcher.calibration_value("zehn und drei"), Some(103));
+    assert_eq!(matcher.calibration_value("ZWÖLF"), Some(1212));
+    assert_eq!(
+        matcher.calibration_sum(&["achtzehn", "7 nein 4"]),
+        Some(810 + 74)
+    );
+}
+
+#[test]
+fn large_words_do_not_overflow() {
+    let vocabulary = "[words]\nmillion = 1000000\nlots = 4294967295\n"
+        .parse::<Vocabulary>()
+        .unwrap();
+    let matcher = DigitMatcher::from_vocabulary(&vocabulary);
+
+    assert_eq!(
+        matcher.calibration_value("a million and a million"),
+        Some(10_000_001_000_000)
+    );
+    assert_eq!(matcher.calibration_value("lots of lots"), None);
+    assert_eq!(matcher.calibration_sum(&["million", "lots and lots"]), None);
+}
+
+#[test]
+fn vocabularies_can_match_backwards() {
+    let vocabulary = "\
+reversed = true
+
+[words]
+# Quoted words work too
+\"one\" = 1
+two = 2 # trailing comments are ignored
+"
+    .parse::<Vocabulary>()
+    .unwrap();
+    let matcher = DigitMatcher::from_vocabulary(&vocabulary);
+
+    assert_eq!(vocabulary.get("eno"), Some(1));
+    assert_eq!(matcher.first_and_last("owtxxeno"), Some((2, 1)));
+    // Without the setting case still matters
+    assert_eq!(matcher.first_and_last("ONE two"), Some((2, 2)));
+}
+
+#[test]
+fn builtin_vocabularies_match_the_puzzle() {
+    let english = DigitMatcher::from_vocabulary(&Vocabulary::english());
+
+    assert_eq!(
+        english.calibration_sum(&EXAMPLE_2.lines().collect::<Vec<_>>()),
+        Some(281)
+    );
+    assert_eq!(Vocabulary::digits().words().count(), 9);
+    assert!(Vocabulary::english()
+        .case_insensitive(true)
+        .is_case_insensitive());
+}
+
+#[test]
+fn invalid_vocabularies_are_reported() {
+    let err = "[words]\none = 1\nuno = one\n"
+        .parse::<Vocabulary>()
+        .unwrap_err();
+    assert_eq!((err.line(), err.column(), err.text()), (3, 7, "one"));
+    assert_eq!(err.reason(), "invalid number");
+
+    let err = "[words]\none = 1\none = 2\n"
+        .parse::<Vocabulary>()
+        .unwrap_err();
+    assert_eq!(
+        (err.line(), err.column(), err.reason()),
+        (3, 1, "word already means 1")
+    );
+
+    let err = "backwards = true\n".parse::<Vocabulary>().unwrap_err();
+    assert_eq!(err.reason(), "unknown setting");
+
+    let err = "[words]\nseven\n".parse::<Vocabulary>().unwrap_err();
+    assert_eq!(
+        (err.line(), err.column(), err.reason()),
+        (2, 6, "missing \"=\"")
+    );
+}
@@ -0,0 +1,287 @@
+use common::{
+    field::{Direction, Field, TileError},
+    parse::ParseError,
+    solution::Solution,
+};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Field<Tile>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Field<Tile>, ParseError> {
+        let field: Field<Tile> = input.parse()?;
+
+        let (start, other) = {
+            let mut starts = field.positions_where(|tile| *tile == Tile::Start);
+            (starts.next(), starts.next())
+        };
+
+        let start_error =
+            |(x, y): (usize, usize), reason| ParseError::new(y + 1, x + 1, "S", reason);
+        let start = start.ok_or_else(|| ParseError::new(1, 1, "", "missing start"))?;
+
+        if let Some(other) = other {
+            return Err(start_error(other, "more than one start"));
+        }
+
+        let connecting = Direction::ALL
+            .into_iter()
+            .filter(|dir| {
+                field
+                    .step(start, *dir)
+                    .is_some_and(|pos| field.get(pos).apply_to_direction(&mut dir.clone()).is_ok())
+            })
+            .count();
+
+        if connecting != 2 {
+            return Err(start_error(start, "start should connect to two pipes"));
+        }
+
+        if find_loop(&field).is_none() {
+            return Err(start_error(start, "start is not on a loop"));
+        }
+
+        Ok(field)
+    }
+
+    fn part1(field: &Field<Tile>) -> usize {
+        let (_path, length) = find_loop(field).expect("parse checks the start is on a loop");
+
+        length / 2
+    }
+
+    fn part2(field: &Field<Tile>) -> usize {
+        let (path, _length) = find_loop(field).expect("parse checks the start is on a loop");
+
+        // Only the pipes in the loop can enclose anything, so every other tile is treated as ground
+        let mut loop_only = Field::new_filled(field.num_rows(), field.num_cols(), Tile::Ground);
+        loop_only.overlay(&path);
+
+        loop_only
+            .iter()
+            .filter(|(_pos, tile)| **tile == Tile::Ground)
+            .filter(|(pos, _tile)| {
+                let mut prev_tile = None;
+                let mut count = 0;
+                for &tile in loop_only.row(pos.1).skip(pos.0) {
+                    if tile == Tile::Ground || tile == Tile::Horizontal {
+                        continue;
+                    }
+
+                    if tile == Tile::Vertical {
+                        count += 1;
+                        continue;
+                    }
+
+                    if let Some(prev_tile) = prev_tile.take() {
+                        let top_prev = match prev_tile {
+                            Tile::TR => true,
+                            Tile::TL => true,
+                            Tile::BR => false,
+                            Tile::BL => false,
+                            _ => unreachable!(),
+                        };
+
+                        let top = match tile {
+                            Tile::TR => true,
+                            Tile::TL => true,
+                            Tile::BR => false,
+                            Tile::BL => false,
+                            _ => unreachable!(),
+                        };
+
+                        if top != top_prev {
+                            count += 1;
+                        }
+                    } else {
+                        prev_tile = Some(tile);
+                    }
+                }
+
+                count % 2 == 1
+            })
+            // .for_each(|val| println!("{:?}", val));
+            .count()
+    }
+}
+
+/// Follows the loop through the start tile, returning the tiles that make up the loop (with the
+/// start tile replaced by the pipe it must be) and the number of tiles in it, or `None` if there
+/// is no start tile or no loop through it.
+fn find_loop(field: &Field<Tile>) -> Option<(Field<Option<Tile>>, usize)> {
+    let start = field.iter().find(|(_, tile)| **tile == Tile::Start)?;
+
+    let (mut path, length, start_dir) = Direction::ALL.iter().find_map(|dir| {
+        let loop_iter = LoopIter {
+            field,
+            dir: *dir,
+            position: start.0,
+        };
+        let mut path = Field::new_filled(field.num_rows(), field.num_cols(), None);
+        let mut length = 1;
+        for val in loop_iter {
+            match val {
+                Err(()) => return None,
+                Ok((pos, tile)) => *path.get_mut(pos) = Some(tile),
+            }
+            length += 1;
+        }
+
+        Some((path, length, dir))
+    })?;
+
+    let (other_dir, _) = Direction::ALL
+        .iter()
+        .filter(|dir| **dir != *start_dir)
+        .filter_map(|dir| Some((dir, field.step(start.0, *dir)?)))
+        .filter_map(|(dir, pos)| Some((dir, (*path.get(pos))?)))
+        .find(|(dir, tile)| match dir {
+            Direction::Up => *tile == Tile::BL || *tile == Tile::BR || *tile == Tile::Vertical,
+            Direction::Down => *tile == Tile::TL || *tile == Tile::TR || *tile == Tile::Vertical,
+            Direction::Left => *tile == Tile::BR || *tile == Tile::TR || *tile == Tile::Horizontal,
+            Direction::Right => *tile == Tile::BL || *tile == Tile::TL || *tile == Tile::Horizontal,
+        })?;
+
+    let start_tile = match (start_dir, other_dir) {
+        (Direction::Up, Direction::Down) => Tile::Vertical,
+        (Direction::Up, Direction::Left) => Tile::TL,
+        (Direction::Up, Direction::Right) => Tile::TR,
+        (Direction::Down, Direction::Up) => Tile::Vertical,
+        (Direction::Down, Direction::Left) => Tile::BL,
+        (Direction::Down, Direction::Right) => Tile::BR,
+        (Direction::Left, Direction::Up) => Tile::TL,
+        (Direction::Left, Direction::Down) => Tile::BL,
+        (Direction::Left, Direction::Right) => Tile::Horizontal,
+        (Direction::Right, Direction::Up) => Tile::TR,
+        (Direction::Right, Direction::Down) => Tile::BR,
+        (Direction::Right, Direction::Left) => Tile::Horizontal,
+        _ => unreachable!(),
+    };
+
+    *path.get_mut(start.0) = Some(start_tile);
+
+    Some((path, length))
+}
+
+struct LoopIter<'a> {
+    position: (usize, usize),
+    dir: Direction,
+    field: &'a Field<Tile>,
+}
+
+impl<'a> Iterator for LoopIter<'a> {
+    type Item = Result<((usize, usize), Tile), ()>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Some(pos) = self.field.step(self.position, self.dir) else {
+            return Some(Err(()));
+        };
+        self.position = pos;
+
+        let new_tile = *self.field.get(self.position);
+
+        if new_tile == Tile::Start {
+            return None;
+        }
+
+        if new_tile.apply_to_direction(&mut self.dir).is_err() {
+            return Some(Err(()));
+        }
+
+        Some(Ok((self.position, new_tile)))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Tile {
+    Start,
+    TR,
+    TL,
+    BR,
+    BL,
+    Vertical,
+    Horizontal,
+    Ground,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = TileError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'S' => Ok(Tile::Start),
+            '-' => Ok(Tile::Horizontal),
+            '|' => Ok(Tile::Vertical),
+            '.' => Ok(Tile::Ground),
+            'J' => Ok(Tile::TL),
+            'L' => Ok(Tile::TR),
+            '7' => Ok(Tile::BL),
+            'F' => Ok(Tile::BR),
+            n => Err(TileError(n)),
+        }
+    }
+}
+
+impl Tile {
+    fn apply_to_direction(&self, dir: &mut Direction) -> Result<(), ()> {
+        match self {
+            Tile::Start => Ok(()),
+            Tile::BL => match dir {
+                Direction::Down | Direction::Left => Err(()),
+                Direction::Up => {
+                    *dir = Direction::Left;
+                    Ok(())
+                }
+                Direction::Right => {
+                    *dir = Direction::Down;
+                    Ok(())
+                }
+            },
+            Tile::BR => match dir {
+                Direction::Down | Direction::Right => Err(()),
+                Direction::Up => {
+                    *dir = Direction::Right;
+                    Ok(())
+                }
+                Direction::Left => {
+                    *dir = Direction::Down;
+                    Ok(())
+                }
+            },
+            Tile::TL => match dir {
+                Direction::Up | Direction::Left => Err(()),
+                Direction::Down => {
+                    *dir = Direction::Left;
+                    Ok(())
+                }
+                Direction::Right => {
+                    *dir = Direction::Up;
+                    Ok(())
+                }
+            },
+            Tile::TR => match dir {
+                Direction::Up | Direction::Right => Err(()),
+                Direction::Down => {
+                    *dir = Direction::Right;
+                    Ok(())
+                }
+                Direction::Left => {
+                    *dir = Direction::Up;
+                    Ok(())
+                }
+            },
+            Tile::Vertical => match dir {
+                Direction::Left | Direction::Right => Err(()),
+                Direction::Up | Direction::Down => Ok(()),
+            },
+            Tile::Horizontal => match dir {
+                Direction::Up | Direction::Down => Err(()),
+                Direction::Left | Direction::Right => Ok(()),
+            },
+            Tile::Ground => Err(()),
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use common::solution::Solution;
+use day_10::Day10;
+
+const EXAMPLE_1: &str = "\
+..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ...
+";
+
+const EXAMPLE_2: &str = "\
+...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........
+";
+
+const EXAMPLE_3: &str = "\
+.F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...
+";
+
+#[test]
+fn part1_example() {
+    assert_eq!(Day10::part1(&Day10::parse(EXAMPLE_1).unwrap()), 8);
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(Day10::part2(&Day10::parse(EXAMPLE_2).unwrap()), 4);
+    assert_eq!(Day10::part2(&Day10::parse(EXAMPLE_3).unwrap()), 8);
+}
+
+#[test]
+fn missing_start_is_reported() {
+    let err = Day10::parse("F7\nLJ\n").err().unwrap();
+
+    assert_eq!((err.line(), err.reason()), (1, "missing start"));
+}
+
+#[test]
+fn second_start_is_reported() {
+    let err = Day10::parse("S7\nLS\n").err().unwrap();
+
+    assert_eq!((err.line(), err.column(), err.text()), (2, 2, "S"));
+    assert_eq!(err.reason(), "more than one start");
+}
+
+#[test]
+fn start_off_the_loop_is_reported() {
+    let err = Day10::parse("...\n.S-\n...\n").err().unwrap();
+
+    assert_eq!((err.line(), err.column()), (2, 2));
+    assert_eq!(err.reason(), "start should connect to two pipes");
+
+    let err = Day10::parse("S-7\n|.|\nL-.\n").err().unwrap();
+
+    assert_eq!((err.line(), err.column()), (1, 1));
+    assert_eq!(err.reason(), "start is not on a loop");
+}
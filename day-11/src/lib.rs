@@ -0,0 +1,90 @@
+use common::{
+    field::{Field, TileError},
+    parse::ParseError,
+    solution::Solution,
+};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Field<bool>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Field<bool>, ParseError> {
+        Field::from_str_with(input, |char| match char {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            char => Err(TileError(char)),
+        })
+    }
+
+    fn part1(input: &Field<bool>) -> usize {
+        total_distance(input, 2)
+    }
+
+    fn part2(input: &Field<bool>) -> usize {
+        total_distance(input, 1000000)
+    }
+}
+
+/// Sums the distances between every pair of galaxies after each empty row and column has been
+/// replaced by `expansion` empty rows or columns.
+pub fn total_distance(image: &Field<bool>, expansion: usize) -> usize {
+    let empty_cols = image.empty_cols(|is_galaxy| !is_galaxy).collect::<Vec<_>>();
+    let empty_rows = image.empty_rows(|is_galaxy| !is_galaxy).collect::<Vec<_>>();
+
+    let galaxies = image
+        .positions_where(|is_galaxy| *is_galaxy)
+        .collect::<Vec<_>>();
+
+    PairsIter::new(&galaxies)
+        .map(|(a, b)| {
+            let x_range = if a.0 > b.0 { b.0..a.0 } else { a.0..b.0 };
+            let y_range = if a.1 > b.1 { b.1..a.1 } else { a.1..b.1 };
+
+            let x_num =
+                empty_cols.iter().filter(|x| x_range.contains(*x)).count() * (expansion - 1);
+            let y_num =
+                empty_rows.iter().filter(|y| y_range.contains(*y)).count() * (expansion - 1);
+
+            (x_range.end - x_range.start + x_num) + (y_range.end - y_range.start + y_num)
+        })
+        .sum()
+}
+
+struct PairsIter<'a, T> {
+    slice: &'a [T],
+    current_index: usize,
+    sub_index: usize,
+}
+
+impl<'a, T> PairsIter<'a, T> {
+    fn new(slice: &'a [T]) -> Self {
+        Self {
+            slice,
+            current_index: 0,
+            sub_index: 1,
+        }
+    }
+}
+
+impl<'a, T> Iterator for PairsIter<'a, T> {
+    type Item = (&'a T, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current_index + 1 >= self.slice.len() {
+            return None;
+        }
+
+        let res = (&self.slice[self.current_index], &self.slice[self.sub_index]);
+
+        self.sub_index += 1;
+        if self.sub_index >= self.slice.len() {
+            self.current_index += 1;
+            self.sub_index = self.current_index + 1;
+        }
+
+        Some(res)
+    }
+}
@@ -0,0 +1,28 @@
+use common::solution::Solution;
+use day_11::{total_distance, Day11};
+
+const EXAMPLE: &str = "\
+...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....
+";
+
+#[test]
+fn part1_example() {
+    assert_eq!(Day11::part1(&Day11::parse(EXAMPLE).unwrap()), 374);
+}
+
+#[test]
+fn larger_expansions() {
+    let image = Day11::parse(EXAMPLE).unwrap();
+
+    assert_eq!(total_distance(&image, 10), 1030);
+    assert_eq!(total_distance(&image, 100), 8410);
+}
@@ -0,0 +1,167 @@
+use std::collections::HashMap;
+
+use common::{
+    parse::{self, ParseError},
+    solution::Solution,
+};
+use itertools::Itertools;
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum State {
+    Operational,
+    Damaged,
+    Unknown,
+}
+
+/// A row of springs and the sizes of each contiguous group of damaged springs in it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    pub row: Vec<State>,
+    pub groups: Vec<usize>,
+}
+
+impl Record {
+    /// Replaces the row with `copies` copies of itself separated by unknown springs, and the
+    /// groups with `copies` copies of themselves.
+    pub fn unfold(&self, copies: usize) -> Self {
+        let mut row = self.row.clone();
+
+        row.insert(0, State::Unknown);
+
+        let amount = row.len() * copies;
+        let mut row = row.into_iter().cycle().take(amount);
+        row.next().unwrap();
+        let row = row.collect::<Vec<_>>();
+
+        let amount = self.groups.len() * copies;
+        let groups = self.groups.iter().copied().cycle().take(amount).collect();
+
+        Self { row, groups }
+    }
+
+    /// Counts the number of ways the unknown springs can be filled in to match the groups.
+    pub fn arrangements(&self) -> usize {
+        calculate_number_of_combos(0, 0, &self.groups, &self.row, &mut HashMap::new())
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<Record>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Record>, ParseError> {
+        parse::lines(input)
+            .map(|line| {
+                let mut parts = line.text().split(' ');
+
+                let springs = line.expect(parts.next(), "springs")?;
+                let row = springs
+                    .char_indices()
+                    .map(|(idx, char)| match char {
+                        '.' => Ok(State::Operational),
+                        '#' => Ok(State::Damaged),
+                        '?' => Ok(State::Unknown),
+                        _ => {
+                            Err(line.error(&springs[idx..idx + char.len_utf8()], "unknown spring"))
+                        }
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                let groups: Vec<usize> = line
+                    .expect(parts.next(), "groups")?
+                    .split(',')
+                    .map(|val| line.parse(val, "group size"))
+                    .collect::<Result<_, _>>()?;
+
+                Ok(Record { row, groups })
+            })
+            .collect()
+    }
+
+    fn part1(input: &Vec<Record>) -> usize {
+        input.par_iter().map(Record::arrangements).sum::<usize>()
+    }
+
+    fn part2(input: &Vec<Record>) -> usize {
+        input
+            .par_iter()
+            .map(|record| record.unfold(5).arrangements())
+            .sum::<usize>()
+    }
+}
+
+fn calculate_number_of_combos(
+    group_idx: usize,
+    row_idx: usize,
+    groups: &[usize],
+    row: &[State],
+    cache: &mut HashMap<(usize, usize), usize>,
+) -> usize {
+    if let Some(res) = cache.get(&(group_idx, row_idx)) {
+        return *res;
+    }
+    let mut number_combos = 0;
+
+    for (idx, state) in row
+        .iter()
+        .enumerate()
+        .filter(|(_, state)| **state != State::Operational)
+    {
+        if idx + groups[0] > row.len() {
+            // If there isn't enough room for the next group stop
+            break;
+        }
+
+        if row[idx..idx + groups[0]]
+            .iter()
+            .all(|val| *val == State::Unknown || *val == State::Damaged)
+            && (idx + groups[0] >= row.len() || row[idx + groups[0]] != State::Damaged)
+        {
+            // If we can create a contigous group that is the correct length
+            if groups.len() == 1 {
+                // If there is only one contigous group left
+                if row[idx + groups[0]..].iter().contains(&State::Damaged) {
+                    // If there are more damaged springs later on so we either end processing
+                    // because this can never work (if this is a guaranteed damage) or just try the next spot
+                    if *state == State::Damaged {
+                        break;
+                    }
+                    continue;
+                }
+                number_combos += 1;
+                if *state == State::Damaged {
+                    // If this is damaged, we know this is the last one from the check above and
+                    // there can be no future matches
+                    break;
+                }
+                continue;
+            } else if idx + groups[0] + 1 >= row.len() {
+                // If we have run out of springs in the row stop early
+                break;
+            }
+            // Check the number of combanations of the rest of the row
+            number_combos += calculate_number_of_combos(
+                group_idx + 1,
+                row_idx + idx + groups[0] + 1,
+                &groups[1..],
+                &row[idx + groups[0] + 1..],
+                cache,
+            );
+
+            if *state == State::Damaged {
+                break;
+            }
+        } else if *state == State::Damaged {
+            // If this is a damaged piece and it couldn't be fit stop processing
+            break;
+        }
+    }
+
+    cache.insert((group_idx, row_idx), number_combos);
+
+    number_combos
+}
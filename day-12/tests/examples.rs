@@ -0,0 +1,42 @@
+use common::solution::Solution;
+use day_12::Day12;
+
+const EXAMPLE: &str = "\
+???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1
+";
+
+#[test]
+fn arrangements_example() {
+    let arrangements = Day12::parse(EXAMPLE)
+        .unwrap()
+        .iter()
+        .map(|record| record.arrangements())
+        .collect::<Vec<_>>();
+
+    assert_eq!(arrangements, [1, 4, 1, 1, 4, 10]);
+}
+
+#[test]
+fn unfold_example() {
+    let record = &Day12::parse(".# 1").unwrap()[0];
+
+    assert_eq!(
+        record.unfold(5),
+        Day12::parse(".#?.#?.#?.#?.# 1,1,1,1,1").unwrap()[0]
+    );
+}
+
+#[test]
+fn part1_example() {
+    assert_eq!(Day12::part1(&Day12::parse(EXAMPLE).unwrap()), 21);
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(Day12::part2(&Day12::parse(EXAMPLE).unwrap()), 525152);
+}
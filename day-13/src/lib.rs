@@ -0,0 +1,141 @@
+use common::{
+    field::{Field, TileError},
+    parse::{self, Line, ParseError},
+    solution::Solution,
+};
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum Tile {
+    Ash,
+    Rock,
+}
+
+impl From<&Tile> for char {
+    fn from(tile: &Tile) -> char {
+        match tile {
+            Tile::Ash => '.',
+            Tile::Rock => '#',
+        }
+    }
+}
+
+impl TryFrom<char> for Tile {
+    type Error = TileError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Tile::Ash),
+            '#' => Ok(Tile::Rock),
+            n => Err(TileError(n)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Mirror {
+    Horizontal(usize),
+    Vertical(usize),
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Field<Tile>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Field<Tile>>, ParseError> {
+        let lines = parse::lines(input).collect::<Vec<_>>();
+
+        // Patterns are separated by blank lines, however many there are
+        let patterns = lines
+            .split(|line| line.text().is_empty())
+            .filter(|pattern| !pattern.is_empty())
+            .map(|pattern| {
+                let text = pattern
+                    .iter()
+                    .map(Line::text)
+                    .collect::<Vec<_>>()
+                    .join("\n");
+
+                // Errors are reported relative to the pattern, so shift them to the whole input
+                text.parse::<Field<Tile>>().map_err(|err| {
+                    let line = err.line() + pattern[0].number() - 1;
+                    err.on_line(line)
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if patterns.is_empty() {
+            return Err(ParseError::new(1, 1, "", "missing patterns"));
+        }
+
+        Ok(patterns)
+    }
+
+    fn part1(input: &Vec<Field<Tile>>) -> usize {
+        summarize(input, 0)
+    }
+
+    fn part2(input: &Vec<Field<Tile>>) -> usize {
+        summarize(input, 1)
+    }
+}
+
+/// Finds the line of reflection in every pattern where exactly `smudges` tiles differ from their
+/// reflection and combines them into the puzzle's summary number. Patterns without such a line
+/// add nothing.
+fn summarize(fields: &[Field<Tile>], smudges: usize) -> usize {
+    fields
+        .iter()
+        .filter_map(|field| find_mirror(field, smudges))
+        .map(|mirror| match mirror {
+            Mirror::Horizontal(num) => (num + 1) * 100,
+            Mirror::Vertical(num) => num + 1,
+        })
+        .sum::<usize>()
+}
+
+fn find_mirror(field: &Field<Tile>, smudges: usize) -> Option<Mirror> {
+    {
+        let mut cols = field.cols().enumerate();
+        let mut cols_next = field.cols();
+        cols_next.next();
+
+        while let (Some((x, col)), Some(col_next)) = (cols.next(), cols_next.next()) {
+            if col.zip(col_next).filter(|(a, b)| **a != **b).count() <= smudges
+                && (0..x + 1)
+                    .rev()
+                    .zip(x + 1..field.num_cols())
+                    .map(|(a, b)| (field.col(a), field.col(b)))
+                    .map(|(a, b)| a.zip(b).filter(|(a, b)| **a != **b).count())
+                    .sum::<usize>()
+                    == smudges
+            {
+                return Some(Mirror::Vertical(x));
+            }
+        }
+    }
+
+    {
+        let mut rows = field.rows().enumerate();
+        let mut rows_next = field.rows();
+        rows_next.next();
+
+        while let (Some((y, row)), Some(row_next)) = (rows.next(), rows_next.next()) {
+            if row.zip(row_next).filter(|(a, b)| **a != **b).count() <= smudges
+                && (0..y + 1)
+                    .rev()
+                    .zip(y + 1..field.num_rows())
+                    .map(|(a, b)| (field.row(a), field.row(b)))
+                    .map(|(a, b)| a.zip(b).filter(|(a, b)| **a != **b).count())
+                    .sum::<usize>()
+                    == smudges
+            {
+                return Some(Mirror::Horizontal(y));
+            }
+        }
+    }
+
+    None
+}
@@ -0,0 +1,61 @@
+use common::solution::Solution;
+use day_13::Day13;
+
+const EXAMPLE: &str = "\
+#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.##..##.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#
+";
+
+#[test]
+fn part1_example() {
+    assert_eq!(Day13::part1(&Day13::parse(EXAMPLE).unwrap()), 405);
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(Day13::part2(&Day13::parse(EXAMPLE).unwrap()), 400);
+}
+
+#[test]
+fn errors_point_into_later_patterns() {
+    let err = Day13::parse("#.\n.#\n\n##\n#x\n").unwrap_err();
+
+    assert_eq!((err.line(), err.column(), err.text()), (5, 2, "x"));
+}
+
+#[test]
+fn blank_lines_only_separate_patterns() {
+    let input = Day13::parse("#.\n.#\n\n\n\n##\n#.\n\n").unwrap();
+    assert_eq!(input.len(), 2);
+
+    let crlf = Day13::parse(&EXAMPLE.replace('\n', "\r\n")).unwrap();
+    assert_eq!(Day13::part1(&crlf), 405);
+
+    let err = Day13::parse("#.\n\n\n##\n#x\n").unwrap_err();
+    assert_eq!((err.line(), err.column(), err.text()), (5, 2, "x"));
+
+    let err = Day13::parse("\n\n").unwrap_err();
+    assert_eq!((err.line(), err.reason()), (1, "missing patterns"));
+    assert!(Day13::parse("").is_err());
+}
+
+#[test]
+fn pattern_without_reflection_adds_nothing() {
+    let input = Day13::parse("#.\n.#\n\n#.#\n##.\n").unwrap();
+
+    assert_eq!(Day13::part1(&input), 0);
+    assert_eq!(Day13::part2(&input), 1);
+}
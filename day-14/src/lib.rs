@@ -0,0 +1,109 @@
+use common::{
+    field::{Field, TileError},
+    parse::ParseError,
+    solution::Solution,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Tile {
+    Round,
+    Square,
+    Ground,
+}
+
+impl From<&Tile> for char {
+    fn from(tile: &Tile) -> char {
+        match tile {
+            Tile::Round => 'O',
+            Tile::Square => '#',
+            Tile::Ground => '.',
+        }
+    }
+}
+
+impl TryFrom<char> for Tile {
+    type Error = TileError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'O' => Ok(Tile::Round),
+            '#' => Ok(Tile::Square),
+            '.' => Ok(Tile::Ground),
+            n => Err(TileError(n)),
+        }
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Field<Tile>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Field<Tile>, ParseError> {
+        input.parse()
+    }
+
+    fn part1(input: &Field<Tile>) -> usize {
+        let mut field = input.clone();
+        tilt_north(&mut field);
+
+        load(&field)
+    }
+
+    fn part2(input: &Field<Tile>) -> usize {
+        let mut field = input.clone();
+
+        // The platform after each number of spin cycles, until it starts repeating
+        let mut history = vec![field.clone()];
+
+        loop {
+            spin_cycle(&mut field);
+
+            if let Some(loop_start) = history.iter().position(|seen| *seen == field) {
+                let loop_len = history.len() - loop_start;
+
+                return load(&history[loop_start + (1000000000 - loop_start) % loop_len]);
+            }
+
+            history.push(field.clone());
+        }
+    }
+}
+
+/// Calculates the total load on the north support beams.
+fn load(field: &Field<Tile>) -> usize {
+    field
+        .iter()
+        .filter(|(_, tile)| **tile == Tile::Round)
+        .map(|((_x, y), _)| field.num_rows() - y)
+        .sum()
+}
+
+/// Tilts the platform north, then west, then south, then east.
+fn spin_cycle(field: &mut Field<Tile>) {
+    for _ in 0..4 {
+        tilt_north(field);
+        // Turning the platform brings the next side round to the north
+        field.rotate_cw();
+    }
+}
+
+/// Rolls every round rock as far north as it will go.
+fn tilt_north(field: &mut Field<Tile>) {
+    for x in 0..field.num_cols() {
+        let mut free_y = 0;
+
+        for y in 0..field.num_rows() {
+            match field.get((x, y)) {
+                Tile::Square => free_y = y + 1,
+                Tile::Round => {
+                    field.swap((x, y), (x, free_y));
+                    free_y += 1;
+                }
+                Tile::Ground => {}
+            }
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use common::solution::Solution;
+use day_14::Day14;
+
+const EXAMPLE: &str = "\
+O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....
+";
+
+#[test]
+fn part1_example() {
+    assert_eq!(Day14::part1(&Day14::parse(EXAMPLE).unwrap()), 136);
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(Day14::part2(&Day14::parse(EXAMPLE).unwrap()), 64);
+}
@@ -0,0 +1,126 @@
+use std::fmt::Display;
+
+use common::{
+    parse::{Line, ParseError},
+    solution::Solution,
+};
+
+/// A single step of the initialization sequence, along with the text it was written as.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Step {
+    text: String,
+    box_id: usize,
+    label: String,
+    action: Action,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Action {
+    Add(u8),
+    Remove,
+}
+
+impl Display for Step {
+    /// Writes the step exactly as it appeared in the input, since that is what gets hashed.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text)
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<Step>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Step>, ParseError> {
+        let line = Line::new(1, input.trim_end());
+
+        line.text()
+            .split(',')
+            .map(|step| {
+                if let Some(label) = step.strip_suffix('-') {
+                    Ok(Step {
+                        text: step.to_string(),
+                        label: label.to_string(),
+                        box_id: hash(label) as usize,
+                        action: Action::Remove,
+                    })
+                } else {
+                    let mut parts = step.split('=');
+                    let label = line.expect(parts.next(), "label")?;
+                    let lens = parts
+                        .next()
+                        .ok_or_else(|| line.error(step, "expected \"=\" or \"-\""))?;
+                    let lens = line.parse::<u8>(lens, "focal length")?;
+
+                    if let Some(extra) = parts.next() {
+                        return Err(line.error(extra, "unexpected text after focal length"));
+                    }
+
+                    Ok(Step {
+                        text: step.to_string(),
+                        label: label.to_string(),
+                        box_id: hash(label) as usize,
+                        action: Action::Add(lens),
+                    })
+                }
+            })
+            .collect()
+    }
+
+    fn part1(input: &Vec<Step>) -> usize {
+        input.iter().map(|step| hash(&step.text) as usize).sum()
+    }
+
+    fn part2(input: &Vec<Step>) -> usize {
+        let steps = input.iter();
+
+        let mut boxes: [Vec<(&str, u8)>; 256] = std::array::from_fn(|_| vec![]);
+
+        steps.for_each(|step| {
+            let current_box = &mut boxes[step.box_id];
+
+            match step.action {
+                Action::Add(lens) => {
+                    if let Some(idx) = current_box
+                        .iter()
+                        .position(|(label, _lens)| *label == step.label)
+                    {
+                        current_box[idx].1 = lens
+                    } else {
+                        current_box.push((&step.label, lens));
+                    }
+                }
+                Action::Remove => current_box.retain(|(label, _lens)| *label != step.label),
+            }
+        });
+
+        boxes
+            .into_iter()
+            .enumerate()
+            // .filter_map(|(idx, val)| Some((idx, val?)))
+            .map(|(idx, current_box)| {
+                let box_num = idx + 1;
+                current_box
+                    .into_iter()
+                    .enumerate()
+                    .map(|(idx, (_label, lens))| {
+                        let lens_slot = idx + 1;
+
+                        lens_slot * lens as usize * box_num
+                    })
+                    .sum::<usize>()
+            })
+            .sum::<usize>()
+    }
+}
+
+/// Runs the HASH algorithm over `input`.
+pub fn hash(input: &str) -> u8 {
+    input.chars().fold(0u8, |accum, val| {
+        let ascii = val as u8;
+        ((((accum as u64) + (ascii as u64)) * 17) % 256) as u8
+    })
+}
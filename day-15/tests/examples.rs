@@ -0,0 +1,50 @@
+use common::solution::Solution;
+use day_15::{hash, Day15};
+
+const EXAMPLE: &str = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7\n";
+
+#[test]
+fn hash_example() {
+    assert_eq!(hash("HASH"), 52);
+}
+
+#[test]
+fn steps_display_as_input() {
+    let steps = Day15::parse(EXAMPLE)
+        .unwrap()
+        .iter()
+        .map(|step| step.to_string())
+        .collect::<Vec<_>>();
+
+    assert_eq!(steps.join(","), EXAMPLE.trim());
+}
+
+#[test]
+fn part1_example() {
+    assert_eq!(Day15::part1(&Day15::parse(EXAMPLE).unwrap()), 1320);
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(Day15::part2(&Day15::parse(EXAMPLE).unwrap()), 145);
+}
+
+#[test]
+fn part1_hashes_steps_as_written() {
+    let steps = Day15::parse("a=07,ab--\n").unwrap();
+
+    assert_eq!(steps[0].to_string(), "a=07");
+    assert_eq!(steps[1].to_string(), "ab--");
+    assert_eq!(
+        Day15::part1(&steps),
+        hash("a=07") as usize + hash("ab--") as usize
+    );
+}
+
+#[test]
+fn extra_equals_is_reported() {
+    let err = Day15::parse("rn=1,a=1=2\n").unwrap_err();
+
+    assert_eq!((err.line(), err.column(), err.text()), (1, 10, "2"));
+    assert_eq!(err.reason(), "unexpected text after focal length");
+}
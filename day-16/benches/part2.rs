@@ -0,0 +1,58 @@
+use common::{field::Field, solution::Solution};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day_16::{edge_starts, energize, Day16, Tile};
+
+/// A 110x110 contraption, the size of a real puzzle input, with roughly one tile in ten being a
+/// mirror or splitter.
+fn contraption() -> Field<Tile> {
+    // xorshift, so the layout is the same on every run
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let mut input = String::new();
+
+    for _ in 0..110 {
+        for _ in 0..110 {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+
+            input.push(match state % 40 {
+                0 => '/',
+                1 => '\\',
+                2 => '|',
+                3 => '-',
+                _ => '.',
+            });
+        }
+        input.push('\n');
+    }
+
+    Day16::parse(&input).unwrap()
+}
+
+/// Part 2 the way it used to be done, following every start separately on one thread.
+fn sequential(field: &Field<Tile>) -> usize {
+    edge_starts(field)
+        .map(|(pos, dir)| {
+            energize(field, pos, dir)
+                .iter()
+                .filter(|(_pos, map)| map.is_energized())
+                .count()
+        })
+        .max()
+        .unwrap()
+}
+
+fn bench_part2(c: &mut Criterion) {
+    let field = contraption();
+    assert_eq!(sequential(&field), Day16::part2(&field));
+
+    let mut group = c.benchmark_group("day 16 part 2");
+    group.bench_function("sequential", |b| b.iter(|| sequential(black_box(&field))));
+    group.bench_function("parallel memoized", |b| {
+        b.iter(|| Day16::part2(black_box(&field)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_part2);
+criterion_main!(benches);
@@ -0,0 +1,484 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    io::{self, Write},
+    mem,
+};
+
+use common::{
+    field::{Dir8, Direction, Field, TileError},
+    parse::ParseError,
+    solution::Solution,
+};
+use rayon::prelude::*;
+
+#[derive(Clone, Copy, Debug)]
+pub enum Tile {
+    Empty,
+    ForwardMirror,
+    BackwardMirror,
+    VerticalSplitter,
+    HorizontalSplitter,
+}
+
+impl From<&Tile> for char {
+    fn from(tile: &Tile) -> char {
+        match tile {
+            Tile::Empty => '.',
+            Tile::ForwardMirror => '/',
+            Tile::BackwardMirror => '\\',
+            Tile::VerticalSplitter => '|',
+            Tile::HorizontalSplitter => '-',
+        }
+    }
+}
+
+impl TryFrom<char> for Tile {
+    type Error = TileError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Tile::Empty),
+            '/' => Ok(Tile::ForwardMirror),
+            '\\' => Ok(Tile::BackwardMirror),
+            '|' => Ok(Tile::VerticalSplitter),
+            '-' => Ok(Tile::HorizontalSplitter),
+            n => Err(TileError(n)),
+        }
+    }
+}
+
+impl Tile {
+    const FORWARD_MIRROR: Optic = Optic::ABSORBER
+        .with(Dir8::Up, DirectionMap::of(&[Dir8::Right]))
+        .with(Dir8::Down, DirectionMap::of(&[Dir8::Left]))
+        .with(Dir8::Left, DirectionMap::of(&[Dir8::Down]))
+        .with(Dir8::Right, DirectionMap::of(&[Dir8::Up]));
+
+    const BACKWARD_MIRROR: Optic = Optic::ABSORBER
+        .with(Dir8::Up, DirectionMap::of(&[Dir8::Left]))
+        .with(Dir8::Down, DirectionMap::of(&[Dir8::Right]))
+        .with(Dir8::Left, DirectionMap::of(&[Dir8::Up]))
+        .with(Dir8::Right, DirectionMap::of(&[Dir8::Down]));
+
+    const VERTICAL_SPLITTER: Optic = Optic::TRANSPARENT
+        .with(Dir8::Left, DirectionMap::of(&[Dir8::Up, Dir8::Down]))
+        .with(Dir8::Right, DirectionMap::of(&[Dir8::Up, Dir8::Down]));
+
+    const HORIZONTAL_SPLITTER: Optic = Optic::TRANSPARENT
+        .with(Dir8::Up, DirectionMap::of(&[Dir8::Left, Dir8::Right]))
+        .with(Dir8::Down, DirectionMap::of(&[Dir8::Left, Dir8::Right]));
+
+    pub fn optic(&self) -> &'static Optic {
+        match self {
+            Tile::Empty => &Optic::TRANSPARENT,
+            Tile::ForwardMirror => &Self::FORWARD_MIRROR,
+            Tile::BackwardMirror => &Self::BACKWARD_MIRROR,
+            Tile::VerticalSplitter => &Self::VERTICAL_SPLITTER,
+            Tile::HorizontalSplitter => &Self::HORIZONTAL_SPLITTER,
+        }
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Field<Tile>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Field<Tile>, ParseError> {
+        input.parse()
+    }
+
+    fn part1(field: &Field<Tile>) -> usize {
+        if field.try_get((0, 0)).is_none() {
+            return 0;
+        }
+
+        energized(field, (0, 0), Direction::Right)
+    }
+
+    fn part2(field: &Field<Tile>) -> usize {
+        most_energized(field, edge_starts(field))
+    }
+}
+
+/// Something a beam can pass through, described by the directions it sends a beam off in for each
+/// direction the beam could arrive travelling in.
+pub trait Component {
+    fn outgoing(&self, incoming: Dir8) -> DirectionMap;
+}
+
+impl Component for Tile {
+    fn outgoing(&self, incoming: Dir8) -> DirectionMap {
+        self.optic().outgoing(incoming)
+    }
+}
+
+/// A component built from a table of where it sends beams, so new kinds of component can be made
+/// without writing any code for them. For example, a one-way gate only letting beams through to
+/// the right is `Optic::ABSORBER.with(Dir8::Right, DirectionMap::of(&[Dir8::Right]))`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Optic {
+    outgoing: [DirectionMap; 8],
+}
+
+impl Optic {
+    /// Lets every beam carry on in the direction it was already going.
+    pub const TRANSPARENT: Optic = {
+        let mut outgoing = [DirectionMap::EMPTY; 8];
+        let mut idx = 0;
+
+        while idx < outgoing.len() {
+            outgoing[idx] = DirectionMap::of(&[Dir8::ALL[idx]]);
+            idx += 1;
+        }
+
+        Optic { outgoing }
+    };
+
+    /// Stops every beam.
+    pub const ABSORBER: Optic = Optic {
+        outgoing: [DirectionMap::EMPTY; 8],
+    };
+
+    /// Sends beams arriving travelling in `incoming` off in `outgoing` instead.
+    pub const fn with(mut self, incoming: Dir8, outgoing: DirectionMap) -> Self {
+        self.outgoing[incoming as usize] = outgoing;
+        self
+    }
+}
+
+impl Component for Optic {
+    fn outgoing(&self, incoming: Dir8) -> DirectionMap {
+        self.outgoing[incoming as usize]
+    }
+}
+
+impl From<&Tile> for Optic {
+    fn from(tile: &Tile) -> Self {
+        *tile.optic()
+    }
+}
+
+/// Every position on the edge of the field along with the direction pointing into it.
+pub fn edge_starts<T>(field: &Field<T>) -> impl Iterator<Item = ((usize, usize), Direction)> + '_ {
+    // A field without any tiles has no edges to start from
+    let (width, height) = match (field.num_cols(), field.num_rows()) {
+        (0, _) | (_, 0) => (0, 0),
+        size => size,
+    };
+
+    let first_col = (0..height).map(|y| ((0, y), Direction::Right));
+    let last_col = (0..height).map(move |y| ((width - 1, y), Direction::Left));
+    let first_row = (0..width).map(|x| ((x, 0), Direction::Down));
+    let last_row = (0..width).map(move |x| ((x, height - 1), Direction::Up));
+
+    first_col.chain(last_col).chain(first_row).chain(last_row)
+}
+
+/// Counts the tiles energized by a beam entering `position` travelling in `direction`.
+fn energized<C: Component>(
+    field: &Field<C>,
+    position: (usize, usize),
+    direction: impl Into<Dir8>,
+) -> usize {
+    energize(field, position, direction)
+        .iter()
+        .filter(|(_pos, map)| map.is_energized())
+        .count()
+}
+
+/// A set of directions, such as the directions beams were travelling in as they entered a tile.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DirectionMap(u8);
+
+impl DirectionMap {
+    pub const EMPTY: DirectionMap = DirectionMap(0);
+
+    pub const fn of(dirs: &[Dir8]) -> Self {
+        let mut map = 0;
+        let mut idx = 0;
+
+        while idx < dirs.len() {
+            map |= Self::dir_to_mask(dirs[idx]);
+            idx += 1;
+        }
+
+        DirectionMap(map)
+    }
+
+    /// Records a beam travelling in `dir`, returning `false` if one already had.
+    pub fn add(&mut self, dir: impl Into<Dir8>) -> bool {
+        let mask = Self::dir_to_mask(dir.into());
+        let added = self.0 & mask == 0;
+        self.0 |= mask;
+        added
+    }
+
+    pub fn has(&self, dir: impl Into<Dir8>) -> bool {
+        let mask = Self::dir_to_mask(dir.into());
+        self.0 & mask != 0
+    }
+
+    pub fn is_energized(&self) -> bool {
+        self.0 != 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn iter(self) -> impl Iterator<Item = Dir8> {
+        Dir8::ALL.into_iter().filter(move |dir| self.has(*dir))
+    }
+
+    const fn dir_to_mask(dir: Dir8) -> u8 {
+        1 << dir as u8
+    }
+}
+
+impl From<&DirectionMap> for char {
+    /// Draws energized tiles as `#`, so a `Field<DirectionMap>` debug prints like the puzzle does.
+    fn from(map: &DirectionMap) -> char {
+        if map.is_energized() {
+            '#'
+        } else {
+            '.'
+        }
+    }
+}
+
+/// A tile along with the beams that entered it, for drawing the paths beams took.
+#[derive(Clone, Copy, Debug)]
+pub struct BeamTile {
+    pub tile: Tile,
+    pub beams: DirectionMap,
+}
+
+impl From<&BeamTile> for char {
+    /// Draws empty tiles a single beam passed through as an arrow in its direction, and empty
+    /// tiles several beams passed through as how many there were. Any other tile is drawn as
+    /// itself.
+    fn from(tile: &BeamTile) -> char {
+        if !matches!(tile.tile, Tile::Empty) {
+            return (&tile.tile).into();
+        }
+
+        match (tile.beams.len(), tile.beams.iter().next()) {
+            (0, _) => '.',
+            (1, Some(Dir8::Up)) => '^',
+            (1, Some(Dir8::Down)) => 'v',
+            (1, Some(Dir8::Left)) => '<',
+            (1, Some(Dir8::Right)) => '>',
+            (count, _) => char::from_digit(count as u32, 10).unwrap(),
+        }
+    }
+}
+
+/// Pairs each tile with the beams that entered it, so the result debug prints with arrows
+/// showing where the beams went.
+pub fn beam_paths(field: &Field<Tile>, energized: &Field<DirectionMap>) -> Field<BeamTile> {
+    let tiles = field
+        .iter()
+        .map(|(pos, tile)| BeamTile {
+            tile: *tile,
+            beams: *energized.get(pos),
+        })
+        .collect();
+
+    Field::new(field.num_rows(), field.num_cols(), tiles)
+}
+
+/// Writes the field as a plain (ASCII) PPM image with one pixel per tile, where energized tiles
+/// are yellow, mirrors and splitters that weren't energized are grey and everything else is black.
+pub fn write_ppm(
+    field: &Field<Tile>,
+    energized: &Field<DirectionMap>,
+    out: &mut impl Write,
+) -> io::Result<()> {
+    writeln!(out, "P3\n{} {}\n255", field.num_cols(), field.num_rows())?;
+
+    for (y, row) in field.rows().enumerate() {
+        let pixels = row
+            .enumerate()
+            .map(
+                |(x, tile)| match (energized.get((x, y)).is_energized(), tile) {
+                    (true, _) => "255 220 0",
+                    (false, Tile::Empty) => "0 0 0",
+                    (false, _) => "128 128 128",
+                },
+            )
+            .collect::<Vec<_>>();
+
+        writeln!(out, "{}", pixels.join(" "))?;
+    }
+
+    Ok(())
+}
+
+/// Follows a beam entering `position` travelling in `direction`, recording which directions beams
+/// entered each tile in.
+pub fn energize<C: Component>(
+    field: &Field<C>,
+    position: (usize, usize),
+    direction: impl Into<Dir8>,
+) -> Field<DirectionMap> {
+    let mut traveled = Field::new_filled(field.num_rows(), field.num_cols(), DirectionMap::EMPTY);
+    let mut queue = VecDeque::from([(position, direction.into())]);
+
+    while let Some((position, direction)) = queue.pop_front() {
+        if !traveled.get_mut(position).add(direction) {
+            continue;
+        }
+
+        for dir in field.get(position).outgoing(direction).iter() {
+            if let Some(new_pos) = field.step(position, dir) {
+                queue.push_back((new_pos, dir));
+            }
+        }
+    }
+
+    traveled
+}
+
+/// Finds the most tiles energized by a beam from any of `starts`, following the beams in parallel.
+pub fn most_energized<C: Component + Sync, D: Into<Dir8>>(
+    field: &Field<C>,
+    starts: impl IntoIterator<Item = ((usize, usize), D)>,
+) -> usize {
+    let segments = Segments::new(field);
+
+    starts
+        .into_iter()
+        .map(|(pos, dir)| (pos, dir.into()))
+        .collect::<Vec<_>>()
+        .into_par_iter()
+        .map(|(pos, dir)| segments.energized(field, pos, dir))
+        .max()
+        .unwrap_or(0)
+}
+
+/// A stretch of beam running from one component that splits beams to the next (or until it leaves
+/// the field or is stopped). Nothing along it splits the beam, so a segment only depends on where
+/// it starts and can be shared by every beam that passes through it.
+struct Segment {
+    tiles: Vec<usize>,
+    next: Vec<usize>,
+}
+
+/// The segments leaving every component that splits beams, worked out once and reused for each
+/// start.
+struct Segments {
+    segments: Vec<Segment>,
+    ids: HashMap<((usize, usize), Dir8), usize>,
+}
+
+impl Segments {
+    fn new<C: Component>(field: &Field<C>) -> Self {
+        let mut ids = HashMap::new();
+        let mut starts = Vec::new();
+
+        for (pos, tile) in field.iter() {
+            let splits = Dir8::ALL
+                .into_iter()
+                .map(|dir| tile.outgoing(dir))
+                .filter(|outgoing| outgoing.len() > 1);
+
+            for dir in splits.flat_map(DirectionMap::iter) {
+                if let Some(new_pos) = field.step(pos, dir) {
+                    ids.entry((pos, dir)).or_insert_with(|| {
+                        starts.push((new_pos, dir));
+                        starts.len() - 1
+                    });
+                }
+            }
+        }
+
+        let segments = starts
+            .into_iter()
+            .map(|(pos, dir)| Self::trace(field, &ids, pos, dir))
+            .collect();
+
+        Self { segments, ids }
+    }
+
+    /// Follows a beam until it is split or leaves the field, collecting the tiles it passes
+    /// through and the ids of the segments it carries on into.
+    fn trace<C: Component>(
+        field: &Field<C>,
+        ids: &HashMap<((usize, usize), Dir8), usize>,
+        mut position: (usize, usize),
+        mut direction: Dir8,
+    ) -> Segment {
+        let mut tiles = Vec::new();
+        // Beams can pass straight through splitters, so a segment can loop back on itself
+        let mut seen = HashSet::new();
+
+        while seen.insert((position, direction)) {
+            tiles.push(position.1 * field.num_cols() + position.0);
+
+            let outgoing = field.get(position).outgoing(direction);
+
+            if outgoing.len() > 1 {
+                let next = outgoing
+                    .iter()
+                    .filter_map(|dir| ids.get(&(position, dir)).copied())
+                    .collect();
+
+                return Segment { tiles, next };
+            }
+
+            let Some(dir) = outgoing.iter().next() else {
+                break;
+            };
+            let Some(new_pos) = field.step(position, dir) else {
+                break;
+            };
+
+            position = new_pos;
+            direction = dir;
+        }
+
+        Segment {
+            tiles,
+            next: Vec::new(),
+        }
+    }
+
+    /// Counts the tiles energized by a beam entering `position` travelling in `direction`.
+    fn energized<C: Component>(
+        &self,
+        field: &Field<C>,
+        position: (usize, usize),
+        direction: Dir8,
+    ) -> usize {
+        let first = Self::trace(field, &self.ids, position, direction);
+
+        let mut energized = vec![false; field.num_cols() * field.num_rows()];
+        let mut visited = vec![false; self.segments.len()];
+        let mut count = 0;
+
+        let mut queue = vec![&first];
+
+        while let Some(segment) = queue.pop() {
+            for &tile in &segment.tiles {
+                if !mem::replace(&mut energized[tile], true) {
+                    count += 1;
+                }
+            }
+
+            for &id in &segment.next {
+                if !mem::replace(&mut visited[id], true) {
+                    queue.push(&self.segments[id]);
+                }
+            }
+        }
+
+        count
+    }
+}
@@ -0,0 +1,230 @@
+use common::field::{Dir8, Direction, Field};
+use common::solution::Solution;
+use day_16::{
+    beam_paths, edge_starts, energize, most_energized, write_ppm, Day16, DirectionMap, Optic, Tile,
+};
+
+const EXAMPLE: &str = r".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....
+";
+
+#[test]
+fn part1_example() {
+    assert_eq!(Day16::part1(&Day16::parse(EXAMPLE).unwrap()), 46);
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(Day16::part2(&Day16::parse(EXAMPLE).unwrap()), 51);
+}
+
+#[test]
+fn empty_field_has_nothing_energized() {
+    for input in ["", "\n\n"] {
+        let field = Day16::parse(input).unwrap();
+
+        assert_eq!(edge_starts(&field).count(), 0);
+        assert_eq!(Day16::part1(&field), 0);
+        assert_eq!(Day16::part2(&field), 0);
+    }
+}
+
+#[test]
+fn unknown_tile_is_reported() {
+    let err = Day16::parse(".|.\n.x.\n").unwrap_err();
+
+    assert_eq!((err.line(), err.column(), err.text()), (2, 2, "x"));
+}
+
+#[test]
+fn energize_records_beam_directions() {
+    let field = Day16::parse(EXAMPLE).unwrap();
+    let energized = energize(&field, (0, 0), Direction::Right);
+
+    assert_eq!(
+        energized
+            .iter()
+            .filter(|(_, map)| map.is_energized())
+            .count(),
+        46
+    );
+    // The splitter at (1, 0) sends the beam down rather than letting it carry on to the right
+    assert!(energized.get((1, 0)).has(Direction::Right));
+    assert!(energized.get((1, 1)).has(Direction::Down));
+    assert!(!energized.get((1, 1)).has(Direction::Right));
+    assert!(!energized.get((7, 0)).is_energized());
+}
+
+#[test]
+fn long_beams_do_not_overflow_the_stack() {
+    let row = ".".repeat(100_000);
+    let field = Day16::parse(&format!("{row}\n")).unwrap();
+
+    assert_eq!(Day16::part1(&field), 100_000);
+}
+
+#[test]
+fn part2_matches_following_each_start() {
+    // The splitters and mirrors here send beams round in loops, through splitters both ways
+    let looping = "\
+.\\.../.
+.|....|
+.\\-../.
+.......
+../|\\..
+..\\./..
+";
+
+    for input in [EXAMPLE, looping] {
+        let field = Day16::parse(input).unwrap();
+        let most = edge_starts(&field)
+            .map(|(pos, dir)| {
+                energize(&field, pos, dir)
+                    .iter()
+                    .filter(|(_, map)| map.is_energized())
+                    .count()
+            })
+            .max()
+            .unwrap();
+
+        assert_eq!(Day16::part2(&field), most);
+    }
+}
+
+fn rows(field: &Field<char>) -> Vec<String> {
+    field.rows().map(|row| row.collect()).collect()
+}
+
+#[test]
+fn energized_tiles_render_like_the_puzzle() {
+    let field = Day16::parse(EXAMPLE).unwrap();
+    let energized = energize(&field, (0, 0), Direction::Right);
+
+    assert_eq!(
+        rows(&energized.map(|tile| char::from(tile))),
+        [
+            "######....",
+            ".#...#....",
+            ".#...#####",
+            ".#...##...",
+            ".#...##...",
+            ".#...##...",
+            ".#..####..",
+            "########..",
+            ".#######..",
+            ".#...#.#..",
+        ]
+    );
+}
+
+#[test]
+fn beam_paths_render_as_arrows() {
+    let field = Day16::parse("..\\\n..|\n.\\/\n").unwrap();
+    let energized = energize(&field, (0, 0), Direction::Right);
+
+    assert_eq!(
+        rows(&beam_paths(&field, &energized).map(|tile| char::from(tile))),
+        [">2\\", ".^|", ".\\/"]
+    );
+}
+
+#[test]
+fn ppm_has_a_pixel_per_tile() {
+    let field = Day16::parse(".|\n..\n").unwrap();
+    let energized = energize(&field, (0, 0), Direction::Right);
+
+    let mut out = Vec::new();
+    write_ppm(&field, &energized, &mut out).unwrap();
+
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "P3\n2 2\n255\n255 220 0 255 220 0\n0 0 0 255 220 0\n"
+    );
+}
+
+/// Parses a field of custom components, where `>` only lets beams through travelling right, `#`
+/// stops every beam and `x` sends beams arriving along a row or column off along both diagonals
+/// ahead of it.
+fn custom_optics(input: &str) -> Field<Optic> {
+    let diagonal_splitter = Optic::TRANSPARENT
+        .with(
+            Dir8::Right,
+            DirectionMap::of(&[Dir8::UpRight, Dir8::DownRight]),
+        )
+        .with(
+            Dir8::Left,
+            DirectionMap::of(&[Dir8::UpLeft, Dir8::DownLeft]),
+        )
+        .with(Dir8::Up, DirectionMap::of(&[Dir8::UpLeft, Dir8::UpRight]))
+        .with(
+            Dir8::Down,
+            DirectionMap::of(&[Dir8::DownLeft, Dir8::DownRight]),
+        );
+
+    Field::from_str_with(input, |c| match c {
+        '>' => Ok(Optic::ABSORBER.with(Dir8::Right, DirectionMap::of(&[Dir8::Right]))),
+        '#' => Ok(Optic::ABSORBER),
+        'x' => Ok(diagonal_splitter),
+        c => Tile::try_from(c).map(|tile| Optic::from(&tile)),
+    })
+    .unwrap()
+}
+
+#[test]
+fn optics_match_tiles() {
+    let tiles = Day16::parse(EXAMPLE).unwrap();
+    let optics = custom_optics(EXAMPLE);
+
+    assert_eq!(
+        energize(&optics, (0, 0), Direction::Right),
+        energize(&tiles, (0, 0), Direction::Right)
+    );
+    assert_eq!(most_energized(&optics, edge_starts(&optics)), 51);
+}
+
+#[test]
+fn custom_components_redirect_beams() {
+    let field = custom_optics(
+        "\
+..>..#.
+.......
+.......
+x......
+",
+    );
+
+    // The gate lets beams heading right through and the absorber stops them
+    let energized = energize(&field, (0, 0), Direction::Right);
+    assert_eq!(
+        rows(&energized.map(|map| char::from(map))),
+        ["######.", ".......", ".......", "......."]
+    );
+
+    // Beams heading left energize the gate, but go no further
+    let energized = energize(&field, (4, 0), Direction::Left);
+    assert_eq!(
+        rows(&energized.map(|map| char::from(map))),
+        ["..###..", ".......", ".......", "......."]
+    );
+
+    // The diagonal splitter sends a beam heading right off up and down diagonally, and the one
+    // heading down leaves the field straight away
+    let energized = energize(&field, (0, 3), Direction::Right);
+    assert!(energized.get((1, 2)).has(Dir8::UpRight));
+    assert!(energized.get((3, 0)).has(Dir8::UpRight));
+    assert_eq!(
+        energized
+            .iter()
+            .filter(|(_, map)| map.is_energized())
+            .count(),
+        4
+    );
+}
@@ -0,0 +1,163 @@
+use std::{collections::BTreeMap, str::FromStr};
+
+use common::{
+    parse::{self, Line, ParseError},
+    solution::Solution,
+};
+
+/// The cubes in the bag for part 1.
+const BAG: [(&str, u32); 3] = [("red", 12), ("green", 13), ("blue", 14)];
+
+/// A number of cubes of each colour, such as those shown in one round of a game or those in a
+/// bag. Colours can be called anything, including names with spaces in.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Draw {
+    cubes: BTreeMap<String, u32>,
+}
+
+impl Draw {
+    /// The number of `colour` cubes, which is 0 for colours that aren't in the draw.
+    pub fn get(&self, colour: &str) -> u32 {
+        self.cubes.get(colour).copied().unwrap_or(0)
+    }
+
+    /// Adds `count` cubes of `colour` to any already in the draw.
+    pub fn add(&mut self, colour: impl Into<String>, count: u32) {
+        *self.cubes.entry(colour.into()).or_default() += count;
+    }
+
+    /// Each colour in the draw along with the number of cubes of it, in alphabetical order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, u32)> + '_ {
+        self.cubes
+            .iter()
+            .map(|(colour, count)| (colour.as_str(), *count))
+    }
+
+    /// Whether these cubes could all have been taken out of `bag` at once.
+    pub fn fits_in(&self, bag: &Draw) -> bool {
+        self.iter().all(|(colour, count)| count <= bag.get(colour))
+    }
+}
+
+impl<S: Into<String>> FromIterator<(S, u32)> for Draw {
+    fn from_iter<T: IntoIterator<Item = (S, u32)>>(iter: T) -> Self {
+        let mut draw = Draw::default();
+
+        for (colour, count) in iter {
+            draw.add(colour, count);
+        }
+
+        draw
+    }
+}
+
+impl FromStr for Draw {
+    type Err = ParseError;
+
+    /// Parses cubes listed like `3 blue, 4 red`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_draw(Line::new(1, s), s)
+    }
+}
+
+/// A game along with the cubes shown in each of its rounds.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Game {
+    pub id: u32,
+    pub rounds: Vec<Draw>,
+}
+
+impl Game {
+    /// Whether every round could have been drawn from `bag`.
+    pub fn is_possible_with(&self, bag: &Draw) -> bool {
+        self.rounds.iter().all(|round| round.fits_in(bag))
+    }
+
+    /// The fewest cubes of each colour the bag could have held for every round to be possible.
+    pub fn minimum_bag(&self) -> Draw {
+        let mut bag = BTreeMap::<String, u32>::new();
+
+        for (colour, count) in self.rounds.iter().flat_map(Draw::iter) {
+            let max = bag.entry(colour.to_string()).or_default();
+            *max = (*max).max(count);
+        }
+
+        Draw { cubes: bag }
+    }
+
+    /// The numbers of cubes of each of `colours` in the minimum bag multiplied together, so a
+    /// colour that is never shown makes the power 0.
+    pub fn power(&self, colours: &[&str]) -> u32 {
+        let bag = self.minimum_bag();
+
+        colours.iter().map(|colour| bag.get(colour)).product()
+    }
+}
+
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_game(Line::new(1, s))
+    }
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Game>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<Game>, ParseError> {
+        parse::lines(input).map(parse_game).collect()
+    }
+
+    fn part1(input: &Vec<Game>) -> u32 {
+        let bag = Draw::from_iter(BAG);
+
+        input
+            .iter()
+            .filter(|game| game.is_possible_with(&bag))
+            .map(|game| game.id)
+            .sum()
+    }
+
+    fn part2(input: &Vec<Game>) -> u32 {
+        let colours = BAG.map(|(colour, _)| colour);
+
+        input.iter().map(|game| game.power(&colours)).sum()
+    }
+}
+
+fn parse_game(line: Line) -> Result<Game, ParseError> {
+    let mut parts = line.text().trim_start_matches("Game ").split(':');
+    let id: u32 = line.parse(line.expect(parts.next(), "game id")?, "game id")?;
+    let rounds = line
+        .expect(parts.next(), "rounds")?
+        .split(';')
+        .map(|round| parse_draw(line, round))
+        .collect::<Result<_, _>>()?;
+
+    Ok(Game { id, rounds })
+}
+
+/// Parses `text`, which is part of `line`, as cubes listed like `3 blue, 4 red`.
+fn parse_draw(line: Line, text: &str) -> Result<Draw, ParseError> {
+    let mut draw = Draw::default();
+
+    for cubes in text.split(',') {
+        // Everything after the count is the colour, so colours can be more than one word
+        let mut parts = cubes.trim().splitn(2, char::is_whitespace);
+        let count: u32 = line.parse(line.expect(parts.next(), "cube count")?, "cube count")?;
+        let colour = parts
+            .next()
+            .map(str::trim)
+            .filter(|colour| !colour.is_empty());
+        let colour = line.expect(colour, "cube colour")?;
+
+        draw.add(colour, count);
+    }
+
+    Ok(draw)
+}
@@ -0,0 +1,95 @@
+use common::solution::Solution;
+use day_2::{Day2, Draw, Game};
+
+const EXAMPLE: &str = "\
+Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+";
+
+#[test]
+fn parse_example() {
+    let games = Day2::parse(EXAMPLE).unwrap();
+
+    assert_eq!(games.len(), 5);
+    assert_eq!(
+        games[0],
+        Game {
+            id: 1,
+            rounds: vec![
+                Draw::from_iter([("blue", 3), ("red", 4)]),
+                Draw::from_iter([("red", 1), ("green", 2), ("blue", 6)]),
+                Draw::from_iter([("green", 2)]),
+            ]
+        }
+    );
+}
+
+#[test]
+fn minimum_bag_and_power() {
+    let game: Game = "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red"
+        .parse()
+        .unwrap();
+
+    assert_eq!(
+        game.minimum_bag(),
+        Draw::from_iter([("red", 20), ("green", 13), ("blue", 6)])
+    );
+    assert_eq!(game.power(&["red", "green", "blue"]), 1560);
+    assert!(!game.is_possible_with(&Draw::from_iter([("red", 12), ("green", 13), ("blue", 14)])));
+    assert!(game.is_possible_with(&game.minimum_bag()));
+}
+
+#[test]
+fn colours_can_be_anything() {
+    let game: Game = "Game 7: 2 mauve, 1 teal; 3 mauve, 1 mauve".parse().unwrap();
+
+    assert_eq!(game.rounds[1].get("mauve"), 4);
+    assert_eq!(game.rounds[1].get("teal"), 0);
+    assert_eq!(
+        game.minimum_bag().iter().collect::<Vec<_>>(),
+        [("mauve", 4), ("teal", 1)]
+    );
+    assert_eq!(game.power(&["mauve", "teal"]), 4);
+    assert!(!game.is_possible_with(&"4 mauve".parse().unwrap()));
+    assert!(game.is_possible_with(&"4 mauve, 1 teal, 9 red".parse().unwrap()));
+
+    let draw: Draw = "2 light blue, 1 blue".parse().unwrap();
+    assert_eq!((draw.get("light blue"), draw.get("blue")), (2, 1));
+}
+
+#[test]
+fn part1_example() {
+    assert_eq!(Day2::part1(&Day2::parse(EXAMPLE).unwrap()), 8);
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(Day2::part2(&Day2::parse(EXAMPLE).unwrap()), 2286);
+}
+
+#[test]
+fn invalid_cube_count_is_reported() {
+    let err = Day2::parse("Game 1: 3 blue\nGame 2: 1 purple, x red\n").unwrap_err();
+
+    assert_eq!((err.line(), err.column(), err.text()), (2, 19, "x"));
+    assert_eq!(err.reason(), "invalid cube count");
+}
+
+#[test]
+fn missing_colour_is_reported() {
+    let err = "Game 4: 3 blue; 2".parse::<Game>().unwrap_err();
+
+    assert_eq!((err.line(), err.reason()), (1, "missing cube colour"));
+}
+
+#[test]
+fn missing_colour_has_no_power() {
+    let input = Day2::parse("Game 1: 3 red, 2 green\nGame 2: 1 red; 2 green, 1 blue\n").unwrap();
+
+    assert_eq!(input[0].power(&["red", "green", "blue"]), 0);
+    assert_eq!(input[0].power(&["red", "green"]), 6);
+    assert_eq!(Day2::part2(&input), 2);
+}
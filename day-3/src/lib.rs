@@ -0,0 +1,124 @@
+use std::ops::RangeInclusive;
+
+use common::{field::Field, parse::ParseError, solution::Solution};
+
+/// A number in the schematic along with the row and columns it spans.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Number {
+    pub value: u32,
+    pub row: usize,
+    pub cols: RangeInclusive<usize>,
+}
+
+/// The engine schematic along with every number in it. Each cell is indexed by the number covering
+/// it, so looking up the numbers around a cell doesn't need to search through all of them.
+pub struct Schematic {
+    grid: Field<char>,
+    numbers: Vec<Number>,
+    index: Field<Option<usize>>,
+}
+
+impl Schematic {
+    pub fn new(grid: Field<char>) -> Self {
+        let numbers = grid
+            .numbers(|char| char.to_digit(10))
+            .map(|((cols, row), value)| Number { value, row, cols })
+            .collect::<Vec<_>>();
+
+        let mut index = Field::new_filled(grid.num_rows(), grid.num_cols(), None);
+        for (id, number) in numbers.iter().enumerate() {
+            for col in number.cols.clone() {
+                *index.get_mut((col, number.row)) = Some(id);
+            }
+        }
+
+        Self {
+            grid,
+            numbers,
+            index,
+        }
+    }
+
+    pub fn grid(&self) -> &Field<char> {
+        &self.grid
+    }
+
+    /// Every number in the schematic, in reading order.
+    pub fn numbers(&self) -> &[Number] {
+        &self.numbers
+    }
+
+    /// The number with a digit at `pos`, if there is one.
+    pub fn number_at(&self, pos: (usize, usize)) -> Option<&Number> {
+        self.index
+            .try_get(pos)
+            .copied()
+            .flatten()
+            .map(|id| &self.numbers[id])
+    }
+
+    /// The numbers with a digit next to `pos`, including diagonally. A number touching `pos` in
+    /// more than one place is only included once.
+    pub fn numbers_adjacent_to(&self, pos: (usize, usize)) -> impl Iterator<Item = &Number> + '_ {
+        let mut ids = self
+            .grid
+            .neighbors8(pos)
+            .filter_map(|pos| *self.index.get(pos))
+            .collect::<Vec<_>>();
+
+        ids.sort_unstable();
+        ids.dedup();
+
+        ids.into_iter().map(|id| &self.numbers[id])
+    }
+
+    /// The numbers next to a symbol, which is anything other than a digit or `.`.
+    pub fn part_numbers(&self) -> impl Iterator<Item = &Number> + '_ {
+        self.numbers.iter().filter(|number| {
+            self.grid
+                .surrounding(number.cols.clone(), number.row)
+                .any(|pos| is_symbol(*self.grid.get(pos)))
+        })
+    }
+
+    /// Every `*` next to exactly two numbers, along with those numbers.
+    pub fn gears(&self) -> impl Iterator<Item = ((usize, usize), [&Number; 2])> + '_ {
+        self.grid
+            .positions_where(|char| *char == '*')
+            .filter_map(|pos| {
+                let mut numbers = self.numbers_adjacent_to(pos);
+
+                match (numbers.next(), numbers.next(), numbers.next()) {
+                    (Some(first), Some(second), None) => Some((pos, [first, second])),
+                    _ => None,
+                }
+            })
+    }
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Schematic;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Schematic, ParseError> {
+        Ok(Schematic::new(input.parse()?))
+    }
+
+    fn part1(input: &Schematic) -> u32 {
+        input.part_numbers().map(|number| number.value).sum()
+    }
+
+    fn part2(input: &Schematic) -> u32 {
+        input
+            .gears()
+            .map(|(_, [first, second])| first.value * second.value)
+            .sum()
+    }
+}
+
+fn is_symbol(char: char) -> bool {
+    char != '.' && !char.is_ascii_digit()
+}
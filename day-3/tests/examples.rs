@@ -0,0 +1,102 @@
+use common::solution::Solution;
+use day_3::{Day3, Number};
+
+const EXAMPLE: &str = "\
+467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..
+";
+
+#[test]
+fn part1_example() {
+    assert_eq!(Day3::part1(&Day3::parse(EXAMPLE).unwrap()), 4361);
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(Day3::part2(&Day3::parse(EXAMPLE).unwrap()), 467835);
+}
+
+#[test]
+fn numbers_adjacent_to_a_cell() {
+    let schematic = Day3::parse(EXAMPLE).unwrap();
+    let values = |pos| {
+        schematic
+            .numbers_adjacent_to(pos)
+            .map(|number| number.value)
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(values((3, 1)), [467, 35]);
+    assert_eq!(values((3, 4)), [617]);
+    assert_eq!(values((9, 9)), [] as [u32; 0]);
+    assert_eq!(
+        schematic.number_at((6, 2)).map(|number| number.value),
+        Some(633)
+    );
+    assert_eq!(schematic.number_at((5, 2)), None);
+}
+
+#[test]
+fn part_numbers_and_gears() {
+    let schematic = Day3::parse(EXAMPLE).unwrap();
+
+    let part_numbers = schematic
+        .part_numbers()
+        .map(|number| number.value)
+        .collect::<Vec<_>>();
+    assert_eq!(part_numbers, [467, 35, 633, 617, 592, 755, 664, 598]);
+
+    let gears = schematic
+        .gears()
+        .map(|(pos, [first, second])| (pos, first.value, second.value))
+        .collect::<Vec<_>>();
+    assert_eq!(gears, [((3, 1), 467, 35), ((5, 8), 755, 598)]);
+}
+
+#[test]
+fn number_touching_a_gear_twice_counts_once() {
+    let schematic = Day3::parse("123\n.*.\n").unwrap();
+
+    assert_eq!(
+        schematic.numbers_adjacent_to((1, 1)).collect::<Vec<_>>(),
+        [&Number {
+            value: 123,
+            row: 0,
+            cols: 0..=2
+        }]
+    );
+    assert_eq!(schematic.gears().count(), 0);
+}
+
+#[test]
+fn numbers_on_every_edge_and_corner() {
+    let schematic = Day3::parse(
+        "\
+12..34
+5#..+6
+..78..
+9*...0
+",
+    )
+    .unwrap();
+
+    let part_numbers = schematic
+        .part_numbers()
+        .map(|number| number.value)
+        .collect::<Vec<_>>();
+    assert_eq!(part_numbers, [12, 34, 5, 6, 78, 9]);
+
+    let gears = schematic
+        .gears()
+        .map(|(pos, [first, second])| (pos, first.value, second.value))
+        .collect::<Vec<_>>();
+    assert_eq!(gears, [((1, 3), 78, 9)]);
+}
@@ -0,0 +1,80 @@
+use std::collections::{hash_map::RandomState, HashSet};
+
+use common::{
+    parse::{self, ParseError},
+    solution::Solution,
+};
+
+/// A scratchcard's winning numbers and the numbers it has.
+pub struct Card {
+    winning: HashSet<u32, RandomState>,
+    have: HashSet<u32, RandomState>,
+}
+
+impl Card {
+    /// Counts how many of the numbers on this card are winning numbers.
+    pub fn matches(&self) -> usize {
+        self.winning.intersection(&self.have).count()
+    }
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<Card>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<Card>, ParseError> {
+        parse::lines(input)
+            .map(|line| {
+                let mut parts = line.text().split(": ");
+                let _start = line.expect(parts.next(), "card number")?;
+                let mut numbers = line.expect(parts.next(), "numbers")?.split('|');
+                let winning = line.expect(numbers.next(), "winning numbers")?;
+                let have = line.expect(numbers.next(), "numbers you have")?;
+
+                let winning = winning
+                    .split_whitespace()
+                    .map(|number| line.parse::<u32>(number, "number"))
+                    .collect::<Result<_, _>>()?;
+
+                let have = have
+                    .split_whitespace()
+                    .map(|number| line.parse::<u32>(number, "number"))
+                    .collect::<Result<_, _>>()?;
+
+                Ok(Card { winning, have })
+            })
+            .collect()
+    }
+
+    fn part1(input: &Vec<Card>) -> u32 {
+        input
+            .iter()
+            .map(Card::matches)
+            .filter(|count| *count != 0)
+            .map(|count| 1 << (count - 1))
+            .sum()
+    }
+
+    fn part2(input: &Vec<Card>) -> u32 {
+        let mut number_of_cards = vec![1; input.len()];
+
+        input
+            .iter()
+            .map(Card::matches)
+            .enumerate()
+            .filter(|(_, count)| *count != 0)
+            .for_each(|(index, score)| {
+                let number_for_line = number_of_cards[index];
+                // There are no cards past the end of the table to win copies of
+                let last = (index + score).min(number_of_cards.len() - 1);
+                for number in &mut number_of_cards[index + 1..=last] {
+                    *number += number_for_line;
+                }
+            });
+
+        number_of_cards.iter().sum::<u32>()
+    }
+}
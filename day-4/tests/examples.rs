@@ -0,0 +1,40 @@
+use common::solution::Solution;
+use day_4::Day4;
+
+const EXAMPLE: &str = "\
+Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11
+";
+
+#[test]
+fn matches_example() {
+    let matches = Day4::parse(EXAMPLE)
+        .unwrap()
+        .iter()
+        .map(|card| card.matches())
+        .collect::<Vec<_>>();
+
+    assert_eq!(matches, [4, 2, 2, 1, 0, 0]);
+}
+
+#[test]
+fn part1_example() {
+    assert_eq!(Day4::part1(&Day4::parse(EXAMPLE).unwrap()), 13);
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(Day4::part2(&Day4::parse(EXAMPLE).unwrap()), 30);
+}
+
+#[test]
+fn copies_past_the_last_card_are_not_won() {
+    let input = Day4::parse("Card 1: 1 2 | 1 2\nCard 2: 3 | 3\n").unwrap();
+
+    assert_eq!(Day4::part2(&input), 3);
+    assert_eq!(Day4::part2(&Day4::parse("Card 1: 1 2 | 1 2").unwrap()), 1);
+}
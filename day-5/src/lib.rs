@@ -0,0 +1,312 @@
+use common::{
+    parse::{self, ParseError},
+    solution::Solution,
+};
+use itertools::Itertools;
+use std::{
+    collections::{hash_map::RandomState, HashMap, HashSet},
+    ops::RangeInclusive,
+};
+
+/// The seeds to be planted and the map from each category to the next category and the ranges
+/// that convert between them.
+pub struct Almanac {
+    seeds: Vec<u64>,
+    maps: HashMap<String, (String, Interval), RandomState>,
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Almanac;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Almanac, ParseError> {
+        let mut lines = parse::lines(input);
+
+        let seeds_line = lines
+            .next()
+            .ok_or_else(|| ParseError::new(1, 1, "", "missing seeds"))?;
+        let seeds = seeds_line
+            .text()
+            .trim_start_matches("seeds: ")
+            .split(' ')
+            .map(|number| Ok((number, seeds_line.parse::<u64>(number, "seed")?)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Part 2 reads the seeds as pairs of a start and a length, so they have to pair up into
+        // ranges that aren't empty
+        if seeds.len() % 2 != 0 {
+            let (last, _) = seeds[seeds.len() - 1];
+            return Err(seeds_line.error(last, "seed range without a length"));
+        }
+        for (&(_, start), &(len_text, len)) in seeds.iter().tuples() {
+            match len.checked_sub(1).map(|len| start.checked_add(len)) {
+                None => return Err(seeds_line.error(len_text, "empty seed range")),
+                Some(None) => return Err(seeds_line.error(len_text, "seed range too long")),
+                Some(Some(_)) => {}
+            }
+        }
+        let seeds = seeds.into_iter().map(|(_, seed)| seed).collect();
+
+        lines.next();
+
+        let titles = lines
+            .collect::<Vec<_>>()
+            .split(|line| line.text().is_empty())
+            .filter(|map| !map.is_empty())
+            .map(|map| {
+                let title_line = map[0];
+                let mut title = title_line.text().trim_end_matches(" map:").split('-');
+
+                let source = title_line.expect(title.next(), "source category")?;
+                title_line.expect(title.next(), "\"-to-\"")?;
+                let dest = title_line.expect(title.next(), "destination category")?;
+
+                let items: Interval = map[1..]
+                    .iter()
+                    .map(|line| {
+                        let mut vals = line.text().split(' ');
+                        let dest_start: u64 =
+                            line.parse(line.expect(vals.next(), "destination start")?, "number")?;
+                        let source_start: u64 =
+                            line.parse(line.expect(vals.next(), "source start")?, "number")?;
+                        let offset = dest_start as i64 - source_start as i64;
+                        let len = line.expect(vals.next(), "range length")?;
+                        let end = match line.parse::<u64>(len, "number")? {
+                            0 => return Err(line.error(len, "empty range")),
+                            len => source_start + (len - 1),
+                        };
+
+                        Ok(Range {
+                            range: source_start..=end,
+                            offset,
+                        })
+                    })
+                    .collect::<Result<Vec<_>, _>>()?
+                    .into();
+
+                Ok((title_line, source, dest, items))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut maps = HashMap::default();
+        let mut sources = HashMap::new();
+        for (title_line, source, dest, items) in titles {
+            if maps
+                .insert(source.to_string(), (dest.to_string(), items))
+                .is_some()
+            {
+                return Err(title_line.error(source, "duplicate map"));
+            }
+
+            sources.insert(source, (title_line, dest));
+        }
+
+        // Every category has to lead on to another until reaching locations, or seeds could never
+        // be followed all the way through
+        let mut category = "seed";
+        let mut from = (
+            seeds_line,
+            seeds_line.text().split(':').next().unwrap_or_default(),
+        );
+        let mut visited = HashSet::new();
+        while category != "location" {
+            let Some((title_line, dest)) = sources.get(category) else {
+                return Err(from.0.error(from.1, format!("no map from {category}")));
+            };
+
+            if !visited.insert(category) {
+                return Err(from
+                    .0
+                    .error(from.1, format!("maps loop back to {category}")));
+            }
+
+            from = (*title_line, dest);
+            category = dest;
+        }
+
+        Ok(Almanac { seeds, maps })
+    }
+
+    fn part1(input: &Almanac) -> u64 {
+        input.lowest_location(
+            input
+                .seeds
+                .iter()
+                .map(|seed| Range::new(*seed..=*seed))
+                .into(),
+        )
+    }
+
+    fn part2(input: &Almanac) -> u64 {
+        input.lowest_location(
+            input
+                .seeds
+                .iter()
+                .tuples()
+                .map(|(first, len)| Range::new(*first..=first + len - 1))
+                .into(),
+        )
+    }
+}
+
+impl Almanac {
+    /// Maps the seeds through every almanac map and returns the lowest location.
+    fn lowest_location(&self, seeds: Interval) -> u64 {
+        let mut ranges = seeds;
+        let mut current_type = "seed";
+
+        while current_type != "location" {
+            let (destination, map) = &self.maps[current_type];
+
+            ranges = ranges.combine(map).apply_offsets();
+
+            current_type = destination;
+        }
+
+        ranges.min()
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Interval {
+    intervals: Vec<Range>,
+}
+
+#[derive(Clone, Debug)]
+struct Range {
+    range: RangeInclusive<u64>,
+    offset: i64,
+}
+
+impl Range {
+    fn new(range: RangeInclusive<u64>) -> Self {
+        Self { range, offset: 0 }
+    }
+
+    fn new_with_offset(range: RangeInclusive<u64>, offset: i64) -> Self {
+        Self { range, offset }
+    }
+
+    fn apply_offset(self) -> Self {
+        let (start, end) = self.range.into_inner();
+        let (start, end) = (start as i64, end as i64);
+        Self {
+            range: (start + self.offset) as u64..=(end + self.offset) as u64,
+            offset: 0,
+        }
+    }
+}
+
+impl<I: IntoIterator<Item = Range>> From<I> for Interval {
+    fn from(value: I) -> Self {
+        Self {
+            intervals: value.into_iter().collect(),
+        }
+        .normalize()
+    }
+}
+
+impl Interval {
+    fn min(&self) -> u64 {
+        self.intervals
+            .iter()
+            .map(|val| *val.range.start())
+            .min()
+            .unwrap()
+    }
+
+    fn apply_offsets(self) -> Self {
+        Self {
+            intervals: self
+                .intervals
+                .into_iter()
+                .map(|val| val.apply_offset())
+                .collect(),
+        }
+        .normalize()
+    }
+
+    fn normalize(self) -> Self {
+        let mut intervals = self.intervals;
+        intervals.sort_unstable_by_key(|range| *range.range.start());
+
+        let mut index = 0;
+        while index + 1 < intervals.len() {
+            let this = &intervals[index];
+            let next = &intervals[index + 1];
+
+            if this.offset != next.offset {
+                index += 1;
+                continue;
+            }
+
+            if this.range.end() >= next.range.start() {
+                intervals[index] =
+                    Range::new_with_offset(*this.range.start()..=*next.range.end(), this.offset);
+                intervals.remove(index + 1);
+            } else {
+                index += 1;
+            }
+        }
+
+        Self { intervals }
+    }
+
+    fn combine(self, other: &Interval) -> Self {
+        let mut new_intervals = Vec::new();
+
+        let mut idx = 0;
+
+        for mut this_range in self.intervals {
+            loop {
+                if idx >= other.intervals.len() {
+                    new_intervals.push(this_range.clone());
+                    break;
+                }
+
+                let other_range = &other.intervals[idx];
+
+                if (*other_range.range.start() < *this_range.range.start()
+                    && *other_range.range.end() < *this_range.range.start())
+                    || (*other_range.range.start() > *this_range.range.end()
+                        && *other_range.range.end() > *this_range.range.end())
+                {
+                    idx += 1;
+                    continue;
+                }
+
+                if *other_range.range.start() > *this_range.range.start() {
+                    new_intervals.push(Range {
+                        range: *this_range.range.start()..=*other_range.range.start() - 1,
+                        offset: 0,
+                    });
+                }
+
+                let start = (*other_range.range.start()).max(*this_range.range.start());
+
+                if *this_range.range.end() <= *other_range.range.end() {
+                    new_intervals.push(Range {
+                        range: start..=*this_range.range.end(),
+                        offset: other_range.offset,
+                    });
+                    break;
+                }
+
+                new_intervals.push(Range {
+                    range: start..=*other_range.range.end(),
+                    offset: other_range.offset,
+                });
+                this_range.range = *other_range.range.end() + 1..=*this_range.range.end();
+                idx += 1;
+            }
+        }
+
+        Self {
+            intervals: new_intervals,
+        }
+        .normalize()
+    }
+}
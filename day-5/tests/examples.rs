@@ -0,0 +1,108 @@
+use common::solution::Solution;
+use day_5::Day5;
+
+const EXAMPLE: &str = "\
+seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4
+";
+
+#[test]
+fn part1_example() {
+    assert_eq!(Day5::part1(&Day5::parse(EXAMPLE).unwrap()), 35);
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(Day5::part2(&Day5::parse(EXAMPLE).unwrap()), 46);
+}
+
+#[test]
+fn empty_map_keeps_numbers() {
+    let input = Day5::parse("seeds: 1 2\n\nseed-to-location map:\n").unwrap();
+
+    assert_eq!(Day5::part1(&input), 1);
+}
+
+#[test]
+fn missing_map_is_reported() {
+    let err = Day5::parse("seeds: 1 2\n\nseed-to-soil map:\n5 1 2\n")
+        .err()
+        .unwrap();
+
+    assert_eq!((err.line(), err.column(), err.text()), (3, 9, "soil"));
+    assert_eq!(err.reason(), "no map from soil");
+
+    let err = Day5::parse("seeds: 1 2\n").err().unwrap();
+    assert_eq!((err.line(), err.reason()), (1, "no map from seed"));
+}
+
+#[test]
+fn looping_maps_are_reported() {
+    let err = Day5::parse("seeds: 1 1\n\nseed-to-soil map:\n\nsoil-to-seed map:\n")
+        .err()
+        .unwrap();
+
+    assert_eq!((err.line(), err.text()), (5, "seed"));
+    assert_eq!(err.reason(), "maps loop back to seed");
+}
+
+#[test]
+fn empty_range_is_reported() {
+    let err = Day5::parse("seeds: 1 1\n\nseed-to-location map:\n5 1 0\n")
+        .err()
+        .unwrap();
+
+    assert_eq!((err.line(), err.column(), err.text()), (4, 5, "0"));
+    assert_eq!(err.reason(), "empty range");
+}
+
+#[test]
+fn unpaired_seeds_are_reported() {
+    let err = Day5::parse("seeds: 79 14 55\n\nseed-to-location map:\n")
+        .err()
+        .unwrap();
+    assert_eq!((err.line(), err.column(), err.text()), (1, 14, "55"));
+    assert_eq!(err.reason(), "seed range without a length");
+
+    let err = Day5::parse("seeds: 0 0\n\nseed-to-location map:\n")
+        .err()
+        .unwrap();
+    assert_eq!((err.line(), err.column(), err.text()), (1, 10, "0"));
+    assert_eq!(err.reason(), "empty seed range");
+
+    let err = Day5::parse("seeds: 18446744073709551615 2\n\nseed-to-location map:\n")
+        .err()
+        .unwrap();
+    assert_eq!(err.reason(), "seed range too long");
+}
@@ -0,0 +1,137 @@
+use common::{
+    parse::{self, Line, ParseError},
+    solution::Solution,
+};
+
+/// A race's duration and the record distance to beat.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Race {
+    pub time: i64,
+    pub distance: i64,
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Vec<Race>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<Race>, ParseError> {
+        let mut lines = parse::lines(input);
+        let times = parse_numbers(lines.next(), "Time:")?;
+        let distances = parse_numbers(lines.next(), "Distance:")?;
+
+        if times.len() != distances.len() {
+            return Err(ParseError::new(
+                2,
+                1,
+                "",
+                format!("expected {} distances", times.len()),
+            ));
+        }
+
+        Ok(times
+            .into_iter()
+            .zip(distances)
+            .map(|(time, distance)| Race { time, distance })
+            .collect())
+    }
+
+    fn part1(input: &Vec<Race>) -> i64 {
+        input
+            .iter()
+            .map(|race| ways_to_win(race.time, race.distance))
+            .product()
+    }
+
+    fn part2(input: &Vec<Race>) -> i64 {
+        // The races are really one race with the spaces between the numbers removed
+        let time = input
+            .iter()
+            .fold(String::new(), |acc, race| acc + &race.time.to_string())
+            .parse::<i64>()
+            .expect("parse checks the numbers can be joined");
+
+        let distance = input
+            .iter()
+            .fold(String::new(), |acc, race| acc + &race.distance.to_string())
+            .parse::<i64>()
+            .expect("parse checks the numbers can be joined");
+
+        ways_to_win(time, distance)
+    }
+}
+
+/// Parses the numbers following `label` on a line of the input.
+fn parse_numbers(line: Option<Line>, label: &str) -> Result<Vec<i64>, ParseError> {
+    let line = line.ok_or_else(|| ParseError::new(1, 1, "", format!("missing {label:?} line")))?;
+    let numbers = line
+        .text()
+        .strip_prefix(label)
+        .ok_or_else(|| line.error(line.text(), format!("expected {label:?}")))?;
+
+    let parsed = numbers
+        .split_whitespace()
+        .map(|val| line.parse(val, "number"))
+        .collect::<Result<Vec<i64>, _>>()?;
+
+    if parsed.is_empty() {
+        return Err(ParseError::new(
+            line.number(),
+            line.text().chars().count() + 1,
+            "",
+            "missing numbers",
+        ));
+    }
+
+    // Part 2 reads the numbers as one long number, so that has to fit as well
+    if parsed
+        .iter()
+        .map(i64::to_string)
+        .collect::<String>()
+        .parse::<i64>()
+        .is_err()
+    {
+        return Err(line.error(numbers.trim(), "numbers too long to join"));
+    }
+
+    Ok(parsed)
+}
+
+/// Counts how many ways the button can be held to travel further than `distance` in `time`.
+fn ways_to_win(time: i64, distance: i64) -> i64 {
+    // Without two separate roots the boat never gets further than the record
+    if i128::from(time).pow(2) - 4 * i128::from(distance) <= 0 {
+        return 0;
+    }
+
+    let (min, max) = quadratic_formula(-1, time, -distance);
+
+    max - min + 1
+}
+
+fn quadratic_formula(a: i64, b: i64, c: i64) -> (i64, i64) {
+    let a = a as f64;
+    let b = b as f64;
+    let c = c as f64;
+
+    let solution_1 = (-b + (b.powi(2) - 4.0 * a * c).sqrt()) / (2.0 * a);
+    let solution_2 = (-b - (b.powi(2) - 4.0 * a * c).sqrt()) / (2.0 * a);
+
+    let (min, max) = (solution_1.min(solution_2), solution_1.max(solution_2));
+
+    let min = if min == min.ceil() {
+        min + 1.0
+    } else {
+        min.ceil()
+    };
+
+    let max = if max == max.floor() {
+        max - 1.0
+    } else {
+        max.floor()
+    };
+
+    (min as i64, max as i64)
+}
@@ -0,0 +1,72 @@
+use common::solution::Solution;
+use day_6::{Day6, Race};
+
+const EXAMPLE: &str = "\
+Time:      7  15   30
+Distance:  9  40  200
+";
+
+#[test]
+fn parse_example() {
+    assert_eq!(
+        Day6::parse(EXAMPLE).unwrap(),
+        [
+            Race {
+                time: 7,
+                distance: 9
+            },
+            Race {
+                time: 15,
+                distance: 40
+            },
+            Race {
+                time: 30,
+                distance: 200
+            },
+        ]
+    );
+}
+
+#[test]
+fn part1_example() {
+    assert_eq!(Day6::part1(&Day6::parse(EXAMPLE).unwrap()), 288);
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(Day6::part2(&Day6::parse(EXAMPLE).unwrap()), 71503);
+}
+
+#[test]
+fn unwinnable_races_have_no_ways_to_win() {
+    let input = Day6::parse("Time: 3\nDistance: 100\n").unwrap();
+    assert_eq!(Day6::part1(&input), 0);
+    assert_eq!(Day6::part2(&input), 0);
+
+    // Holding the button for 2ms only ties the record
+    let input = Day6::parse("Time: 4\nDistance: 4\n").unwrap();
+    assert_eq!(Day6::part1(&input), 0);
+}
+
+#[test]
+fn missing_numbers_are_reported() {
+    let err = Day6::parse("Time:\nDistance:\n").unwrap_err();
+
+    assert_eq!((err.line(), err.column()), (1, 6));
+    assert_eq!(err.reason(), "missing numbers");
+}
+
+#[test]
+fn mismatched_races_are_reported() {
+    let err = Day6::parse("Time: 7 15\nDistance: 9\n").unwrap_err();
+
+    assert_eq!((err.line(), err.reason()), (2, "expected 2 distances"));
+}
+
+#[test]
+fn numbers_too_long_to_join_are_reported() {
+    let err = Day6::parse("Time: 9999999999 9999999999\nDistance: 1 1\n").unwrap_err();
+
+    assert_eq!((err.line(), err.column()), (1, 7));
+    assert_eq!(err.reason(), "numbers too long to join");
+}
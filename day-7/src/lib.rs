@@ -1,29 +1,36 @@
 use std::{cmp::Ordering, fmt::Display, str::FromStr};
 
+use common::{
+    parse::{self, Line, ParseError},
+    solution::Solution,
+};
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Card {
     Ace,
     King,
     Queen,
+    Jack,
     Joker,
     Number(u8),
 }
 
 impl Card {
-    fn to_number_ord(&self) -> u8 {
+    fn to_number_ord(self) -> u8 {
         match self {
             Card::Ace => 14,
             Card::King => 13,
             Card::Queen => 12,
+            Card::Jack => 11,
             Card::Joker => 1,
-            Card::Number(n) => *n,
+            Card::Number(n) => n,
         }
     }
 }
 
 impl PartialOrd for Card {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        self.to_number_ord().partial_cmp(&other.to_number_ord())
+        Some(self.cmp(other))
     }
 }
 
@@ -42,15 +49,6 @@ impl Display for CardError {
     }
 }
 
-#[derive(Debug)]
-pub struct StringError;
-
-impl Display for StringError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "Could not create hand from string")
-    }
-}
-
 impl TryFrom<char> for Card {
     type Error = CardError;
 
@@ -59,7 +57,7 @@ impl TryFrom<char> for Card {
             'A' => Ok(Card::Ace),
             'K' => Ok(Card::King),
             'Q' => Ok(Card::Queen),
-            'J' => Ok(Card::Joker),
+            'J' => Ok(Card::Jack),
             'T' => Ok(Card::Number(10)),
             '9' => Ok(Card::Number(9)),
             '8' => Ok(Card::Number(8)),
@@ -86,7 +84,7 @@ pub enum HandType {
 }
 
 impl HandType {
-    fn to_num_ord(&self) -> u8 {
+    fn to_num_ord(self) -> u8 {
         match self {
             HandType::FiveOfKind => 6,
             HandType::FourOfKind => 5,
@@ -101,7 +99,7 @@ impl HandType {
 
 impl PartialOrd for HandType {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        self.to_num_ord().partial_cmp(&other.to_num_ord())
+        Some(self.cmp(other))
     }
 }
 
@@ -120,6 +118,12 @@ pub struct Hand {
 
 impl PartialOrd for Hand {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
         let hand = self.hand_type.cmp(&other.hand_type);
 
         match hand {
@@ -127,27 +131,33 @@ impl PartialOrd for Hand {
                 for (mine, other) in self.hand.iter().zip(other.hand.iter()) {
                     match mine.cmp(other) {
                         Ordering::Equal => continue,
-                        n => return Some(n),
+                        n => return n,
                     }
                 }
-                Some(Ordering::Equal)
+                Ordering::Equal
             }
-            n => Some(n),
+            n => n,
         }
     }
 }
 
-impl Ord for Hand {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other).unwrap()
-    }
-}
-
 impl Hand {
     pub fn get_bid(&self) -> u64 {
         self.bid
     }
 
+    /// Treats every jack in the hand as a joker, which is weaker on its own but can stand in for
+    /// any other card when working out the hand type.
+    pub fn jacks_to_jokers(self) -> Self {
+        Self::calc_type(
+            self.hand.map(|card| match card {
+                Card::Jack => Card::Joker,
+                card => card,
+            }),
+            self.bid,
+        )
+    }
+
     fn calc_type(cards: [Card; 5], bid: u64) -> Self {
         let mut amounts = Vec::new();
 
@@ -170,7 +180,7 @@ impl Hand {
         amounts.sort_by_key(|val| val.1);
         amounts.reverse();
 
-        if amounts.len() > 0 {
+        if !amounts.is_empty() {
             amounts[0].1 += num_jokers;
         } else {
             amounts.push((Card::Joker, num_jokers));
@@ -201,28 +211,62 @@ impl Hand {
 }
 
 impl FromStr for Hand {
-    type Err = StringError;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut sides = s.split(" ");
-
-        let mut cards_iter = sides
-            .next()
-            .unwrap()
-            .chars()
-            .map(|card| Card::try_from(card).unwrap());
-
-        let bid = sides.next().unwrap().parse::<u64>().unwrap();
-
-        Ok(Self::calc_type(
-            [
-                cards_iter.next().unwrap(),
-                cards_iter.next().unwrap(),
-                cards_iter.next().unwrap(),
-                cards_iter.next().unwrap(),
-                cards_iter.next().unwrap(),
-            ],
-            bid,
-        ))
+        let line = Line::new(1, s);
+        let mut sides = s.split(' ');
+
+        let cards = line.expect(sides.next(), "cards")?;
+        let mut hand = [Card::Ace; 5];
+        let mut cards_iter = cards.char_indices();
+
+        for card in hand.iter_mut() {
+            let (idx, char) = line.expect(cards_iter.next(), "card")?;
+            *card = Card::try_from(char)
+                .map_err(|_| line.error(&cards[idx..idx + char.len_utf8()], "unknown card"))?;
+        }
+
+        if let Some((idx, _)) = cards_iter.next() {
+            return Err(line.error(&cards[idx..], "too many cards"));
+        }
+
+        let bid = line.parse::<u64>(line.expect(sides.next(), "bid")?, "bid")?;
+
+        Ok(Self::calc_type(hand, bid))
+    }
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<Hand>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Vec<Hand>, ParseError> {
+        parse::lines(input)
+            .map(|line| Hand::from_str(line.text()).map_err(|err| err.on_line(line.number())))
+            .collect()
+    }
+
+    fn part1(input: &Vec<Hand>) -> u64 {
+        total_winnings(input.iter().cloned())
+    }
+
+    fn part2(input: &Vec<Hand>) -> u64 {
+        total_winnings(input.iter().cloned().map(Hand::jacks_to_jokers))
     }
 }
+
+fn total_winnings(hands: impl Iterator<Item = Hand>) -> u64 {
+    let mut hands = hands.collect::<Vec<_>>();
+
+    hands.sort();
+
+    hands
+        .into_iter()
+        .enumerate()
+        .map(|(index, val)| (index as u64 + 1) * val.get_bid())
+        .sum::<u64>()
+}
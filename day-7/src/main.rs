@@ -1,25 +1,7 @@
-use std::{fs, str::FromStr};
+use std::process::ExitCode;
 
-use day_7::Hand;
+use day_7::Day7;
 
-fn main() {
-    let input = fs::read_to_string("input.txt").unwrap();
-
-    let mut hands = input
-        .lines()
-        .map(|line| Hand::from_str(line).unwrap())
-        .collect::<Vec<_>>();
-
-    hands.sort();
-
-    let sum = hands
-        .into_iter()
-        .enumerate()
-        .map(|(index, val)| (index as u64 + 1) * val.get_bid())
-        .sum::<u64>();
-
-    println!("{}", sum);
+fn main() -> ExitCode {
+    common::solution::main::<Day7>(7)
 }
-
-
-
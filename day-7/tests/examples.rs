@@ -0,0 +1,40 @@
+use std::str::FromStr;
+
+use common::solution::Solution;
+use day_7::{Day7, Hand};
+
+const EXAMPLE: &str = "\
+32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483
+";
+
+#[test]
+fn jacks_to_jokers_upgrades_hand() {
+    let hand = Hand::from_str("KTJJT 220").unwrap();
+    assert!(hand < Hand::from_str("QQQJA 483").unwrap());
+
+    let hand = hand.jacks_to_jokers();
+    assert!(hand > Hand::from_str("QQQJA 483").unwrap().jacks_to_jokers());
+    assert!(hand > Hand::from_str("T55J5 684").unwrap().jacks_to_jokers());
+}
+
+#[test]
+fn part1_example() {
+    assert_eq!(Day7::part1(&Day7::parse(EXAMPLE).unwrap()), 6440);
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(Day7::part2(&Day7::parse(EXAMPLE).unwrap()), 5905);
+}
+
+#[test]
+fn unknown_card_is_reported() {
+    let err = Day7::parse("32T3K 765\nT55X5 684\n").unwrap_err();
+
+    assert_eq!((err.line(), err.column(), err.text()), (2, 4, "X"));
+    assert_eq!(err.to_string(), "line 2, column 4: unknown card \"X\"");
+}
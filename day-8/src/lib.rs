@@ -0,0 +1,288 @@
+use std::{
+    collections::{hash_map::RandomState, HashMap, HashSet},
+    fmt::Display,
+};
+
+use common::{
+    parse::{self, ParseError},
+    solution::Solution,
+};
+
+/// The left/right instructions (as an index into each node's neighbours) and the map of nodes to
+/// their left and right neighbours.
+pub struct Network {
+    dirs: Vec<usize>,
+    items: HashMap<String, [String; 2], RandomState>,
+}
+
+impl Network {
+    fn step<'a>(&'a self, node: &str, dir_idx: usize) -> &'a str {
+        &self.items[node][dir_idx]
+    }
+}
+
+/// How many steps it takes to reach the end, or why the end can't be reached.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Steps {
+    Reached(usize),
+    /// There are no nodes to start from.
+    NoStart,
+    /// The instructions go round in circles without ever reaching the end.
+    NeverEnds,
+}
+
+impl Display for Steps {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Steps::Reached(steps) => write!(f, "{steps}"),
+            Steps::NoStart => write!(f, "no start node"),
+            Steps::NeverEnds => write!(f, "the end is never reached"),
+        }
+    }
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Network;
+    type Part1 = Steps;
+    type Part2 = Steps;
+
+    fn parse(input: &str) -> Result<Network, ParseError> {
+        let mut lines = parse::lines(input);
+
+        let dirs_line = lines
+            .next()
+            .ok_or_else(|| ParseError::new(1, 1, "", "missing instructions"))?;
+        let dirs = dirs_line
+            .text()
+            .char_indices()
+            .map(|(idx, dir)| match dir {
+                'R' => Ok(1),
+                'L' => Ok(0),
+                _ => Err(dirs_line.error(
+                    &dirs_line.text()[idx..idx + dir.len_utf8()],
+                    "unknown instruction",
+                )),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if dirs.is_empty() {
+            return Err(ParseError::new(1, 1, "", "missing instructions"));
+        }
+
+        lines.next();
+
+        let nodes = lines
+            .map(|line| {
+                let mut parts = line.text().split(" = ");
+
+                let start = line
+                    .expect(parts.next(), "node")?
+                    .trim_start_matches('(')
+                    .trim_end_matches(')');
+
+                let mut next_part = line
+                    .expect(parts.next(), "neighbours")?
+                    .trim_start_matches('(')
+                    .trim_end_matches(')')
+                    .split(", ");
+
+                let (left, right) = (
+                    line.expect(next_part.next(), "left neighbour")?,
+                    line.expect(next_part.next(), "right neighbour")?,
+                );
+
+                Ok((line, start, [left, right]))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let items: HashMap<_, _, _> = nodes
+            .iter()
+            .map(|(_, start, [left, right])| {
+                (start.to_string(), [left.to_string(), right.to_string()])
+            })
+            .collect();
+
+        // Every neighbour has to be defined for the network to be followed from any node
+        for (line, _, neighbours) in &nodes {
+            if let Some(undefined) = neighbours.iter().find(|node| !items.contains_key(**node)) {
+                return Err(line.error(undefined, "undefined node"));
+            }
+        }
+
+        Ok(Network { dirs, items })
+    }
+
+    fn part1(input: &Network) -> Steps {
+        // Networks only meant for ghosts don't have to start at AAA
+        if !input.items.contains_key("AAA") {
+            return Steps::NoStart;
+        }
+
+        let mut current_node = "AAA";
+        let mut steps = 0;
+        let mut seen = HashSet::new();
+
+        while current_node != "ZZZ" {
+            let dir_idx = steps % input.dirs.len();
+
+            // Being back on a node at the same point in the instructions means going round forever
+            if !seen.insert((current_node, dir_idx)) {
+                return Steps::NeverEnds;
+            }
+
+            current_node = input.step(current_node, input.dirs[dir_idx]);
+            steps += 1;
+        }
+
+        Steps::Reached(steps)
+    }
+
+    fn part2(input: &Network) -> Steps {
+        let mut current_nodes = input
+            .items
+            .keys()
+            .filter(|node| node.ends_with('A'))
+            .map(String::as_str)
+            .collect::<Vec<_>>();
+
+        if current_nodes.is_empty() {
+            return Steps::NoStart;
+        }
+
+        let mut history = vec![Vec::<(&str, usize)>::new(); current_nodes.len()];
+        let mut history_done = vec![None; current_nodes.len()];
+
+        let mut current_dir_idx = 0;
+
+        while history_done.iter().any(|val| val.is_none()) {
+            let dir_idx = input.dirs[current_dir_idx];
+
+            for (idx, current_node) in current_nodes.iter_mut().enumerate() {
+                if history_done[idx].is_some() {
+                    continue;
+                }
+
+                if let Some((done_idx, _)) =
+                    history[idx]
+                        .iter()
+                        .enumerate()
+                        .find(|(_, (current_node_check, dir_idx))| {
+                            **current_node_check == **current_node && *dir_idx == current_dir_idx
+                        })
+                {
+                    history_done[idx] = Some(done_idx);
+                } else {
+                    history[idx].push((*current_node, current_dir_idx));
+                }
+
+                *current_node = input.step(current_node, dir_idx);
+            }
+
+            current_dir_idx += 1;
+            if current_dir_idx == input.dirs.len() {
+                current_dir_idx = 0;
+            }
+        }
+
+        let mut loops: Vec<LoopIter> = history
+            .into_iter()
+            .zip(history_done)
+            .map(|(history, loop_idx)| {
+                let loop_idx = loop_idx.unwrap();
+
+                // A ghost can pass through more than one end node each time around its loop
+                let end_idxs = history
+                    .iter()
+                    .enumerate()
+                    .skip(loop_idx)
+                    .filter(|(_, val)| val.0.ends_with('Z'))
+                    .map(|(idx, _)| idx)
+                    .collect::<Vec<_>>();
+
+                LoopIter {
+                    end_idxs,
+                    loop_start: loop_idx,
+                    loop_len: history.len() - loop_idx,
+                    current_loop: 0,
+                    current_end: 0,
+                }
+            })
+            .collect();
+
+        if loops.iter().any(|loop_iter| loop_iter.end_idxs.is_empty()) {
+            return Steps::NeverEnds;
+        }
+
+        // Once every ghost is in its loop, they all line up the same way again after the loop
+        // lengths' least common multiple, so an answer has to come before then
+        let last = loops
+            .iter()
+            .try_fold(1, |lcm, loop_iter| {
+                let len = loop_iter.loop_len;
+                (lcm / gcd(lcm, len)).checked_mul(len)
+            })
+            .and_then(|lcm| {
+                let start = loops.iter().map(|loop_iter| loop_iter.loop_start).max()?;
+                lcm.checked_add(start)
+            })
+            .unwrap_or(usize::MAX);
+
+        let steps = 'outer: loop {
+            let current_val = loops[0].peek();
+            loops[0].advance();
+
+            if current_val > last {
+                return Steps::NeverEnds;
+            }
+
+            for loop_iter in &mut loops[1..] {
+                while loop_iter.peek() < current_val {
+                    loop_iter.advance();
+                }
+
+                if loop_iter.peek() == current_val {
+                    loop_iter.advance();
+                } else {
+                    continue 'outer;
+                }
+            }
+            break current_val;
+        };
+
+        Steps::Reached(steps)
+    }
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Steps through every step at which a ghost is on an end node once it has entered its loop.
+/// Ghosts whose loop has no end nodes in it never get one of these.
+struct LoopIter {
+    end_idxs: Vec<usize>,
+    loop_start: usize,
+    loop_len: usize,
+    current_loop: usize,
+    current_end: usize,
+}
+
+impl LoopIter {
+    pub fn peek(&self) -> usize {
+        self.current_loop * self.loop_len + self.end_idxs[self.current_end]
+    }
+
+    pub fn advance(&mut self) {
+        self.current_end += 1;
+        if self.current_end == self.end_idxs.len() {
+            self.current_end = 0;
+            self.current_loop += 1;
+        }
+    }
+}
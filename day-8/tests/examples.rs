@@ -0,0 +1,115 @@
+use common::solution::Solution;
+use day_8::{Day8, Steps};
+
+const EXAMPLE_1: &str = "\
+RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)
+";
+
+const EXAMPLE_2: &str = "\
+LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)
+";
+
+const EXAMPLE_3: &str = "\
+LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)
+";
+
+#[test]
+fn part1_example() {
+    assert_eq!(
+        Day8::part1(&Day8::parse(EXAMPLE_1).unwrap()),
+        Steps::Reached(2)
+    );
+    assert_eq!(
+        Day8::part1(&Day8::parse(EXAMPLE_2).unwrap()),
+        Steps::Reached(6)
+    );
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(
+        Day8::part2(&Day8::parse(EXAMPLE_3).unwrap()),
+        Steps::Reached(6)
+    );
+}
+
+#[test]
+fn part1_without_start_node() {
+    assert_eq!(
+        Day8::part1(&Day8::parse(EXAMPLE_3).unwrap()),
+        Steps::NoStart
+    );
+}
+
+#[test]
+fn part2_without_start_nodes() {
+    let input = Day8::parse("L\n\nBBB = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)\n").unwrap();
+
+    assert_eq!(Day8::part1(&input), Steps::NoStart);
+    assert_eq!(Day8::part2(&input), Steps::NoStart);
+    assert_eq!(Steps::NoStart.to_string(), "no start node");
+}
+
+#[test]
+fn unreachable_end_is_reported() {
+    let input = Day8::parse("L\n\nAAA = (ZZZ, ZZZ)\nZZZ = (BBB, BBB)\nBBB = (BBB, BBB)\n").unwrap();
+
+    assert_eq!(Day8::part1(&input), Steps::Reached(1));
+    assert_eq!(Day8::part2(&input), Steps::NeverEnds);
+
+    let input = Day8::parse("L\n\nAAA = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)\n").unwrap();
+
+    assert_eq!(Day8::part1(&input), Steps::NeverEnds);
+    assert_eq!(Day8::part2(&input), Steps::NeverEnds);
+}
+
+#[test]
+fn ghosts_that_never_line_up_are_reported() {
+    // The first ghost is only on an end node after odd numbers of steps and the second after even
+    let input = Day8::parse(
+        "L\n\n11A = (11Z, 11Z)\n11Z = (11A, 11A)\n22A = (22B, 22B)\n22B = (22Z, 22Z)\n22Z = (22B, 22B)\n",
+    )
+    .unwrap();
+
+    assert_eq!(Day8::part2(&input), Steps::NeverEnds);
+}
+
+#[test]
+fn missing_instructions_are_reported() {
+    let err = Day8::parse("\n\nAAA = (ZZZ, ZZZ)\nZZZ = (ZZZ, ZZZ)\n")
+        .err()
+        .unwrap();
+
+    assert_eq!((err.line(), err.reason()), (1, "missing instructions"));
+}
+
+#[test]
+fn undefined_node_is_reported() {
+    let err = Day8::parse("L\n\nAAA = (BBB, ZZZ)\nZZZ = (ZZZ, CCC)\n")
+        .err()
+        .unwrap();
+
+    assert_eq!((err.line(), err.column(), err.text()), (3, 8, "BBB"));
+    assert_eq!(err.reason(), "undefined node");
+}
@@ -0,0 +1,60 @@
+use common::{
+    parse::{self, ParseError},
+    solution::Solution,
+};
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<Vec<i64>>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<Vec<i64>>, ParseError> {
+        parse::lines(input)
+            .map(|line| {
+                line.text()
+                    .split(' ')
+                    .map(|val| line.parse::<i64>(val, "number"))
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part1(input: &Vec<Vec<i64>>) -> i64 {
+        // Extrapolating backwards through the reversed history extrapolates the original forwards
+        input
+            .iter()
+            .map(|history| {
+                let mut history = history.clone();
+                history.reverse();
+                process_history(&history)
+            })
+            .sum::<i64>()
+    }
+
+    fn part2(input: &Vec<Vec<i64>>) -> i64 {
+        input
+            .iter()
+            .map(|history| process_history(history))
+            .sum::<i64>()
+    }
+}
+
+fn process_history(history: &[i64]) -> i64 {
+    if history.iter().all(|val| *val == 0) {
+        return 0;
+    }
+
+    let new_history: Vec<_> = history
+        .iter()
+        .take(history.len() - 1)
+        .enumerate()
+        .map(|(idx, first)| {
+            let next = history[idx + 1];
+            next - first
+        })
+        .collect();
+
+    history[0] - process_history(&new_history)
+}
@@ -0,0 +1,18 @@
+use common::solution::Solution;
+use day_9::Day9;
+
+const EXAMPLE: &str = "\
+0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45
+";
+
+#[test]
+fn part1_example() {
+    assert_eq!(Day9::part1(&Day9::parse(EXAMPLE).unwrap()), 114);
+}
+
+#[test]
+fn part2_example() {
+    assert_eq!(Day9::part2(&Day9::parse(EXAMPLE).unwrap()), 2);
+}